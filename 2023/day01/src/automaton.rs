@@ -0,0 +1,118 @@
+use std::collections::VecDeque;
+
+// Finds every occurrence of a set of words in one pass over some bytes (Aho-Corasick).
+// The words' trie is turned into a state machine with a transition for every byte
+// from every state, so that each byte of the text costs a single lookup
+pub struct Automaton<T> {
+    transitions: Vec<[u32; 256]>,
+    // The words that end on reaching each state, longest first
+    matches: Vec<Vec<(usize, T)>>,
+    longest: usize,
+}
+
+impl<T: Copy> Automaton<T> {
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a [u8], T)>) -> Self {
+        let mut transitions = vec![[0; 256]];
+        let mut matches = vec![Vec::new()];
+        let mut longest = 0;
+
+        // The trie, with 0 standing for "no child" as nothing leads back to the root
+        for (word, value) in words {
+            assert!(!word.is_empty(), "words can't be empty");
+
+            let mut state = 0;
+
+            for &byte in word {
+                if transitions[state][byte as usize] == 0 {
+                    transitions[state][byte as usize] = transitions.len() as u32;
+                    transitions.push([0; 256]);
+                    matches.push(Vec::new());
+                }
+
+                state = transitions[state][byte as usize] as usize;
+            }
+
+            matches[state].push((word.len(), value));
+            longest = longest.max(word.len());
+        }
+
+        // Breadth first, so that the state for the longest proper suffix of each
+        // state's word (its fallback) is finished before the state itself
+        let mut fallbacks = vec![0; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect::<VecDeque<_>>();
+
+        while let Some(state) = queue.pop_front() {
+            let fallback = fallbacks[state];
+            let inherited = matches[fallback].clone();
+            matches[state].extend(inherited);
+
+            let fallback_transitions = transitions[fallback];
+
+            for (child, fallback_child) in transitions[state].iter_mut().zip(fallback_transitions) {
+                if *child == 0 {
+                    *child = fallback_child;
+                } else {
+                    fallbacks[*child as usize] = fallback_child as usize;
+                    queue.push_back(*child as usize);
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            matches,
+            longest,
+        }
+    }
+
+    // The length of the longest word
+    pub fn longest(&self) -> usize {
+        self.longest
+    }
+
+    // Every occurrence as (index after its last byte, length, value), in order of
+    // where they end; words ending at the same place come longest first
+    pub fn find_iter<'a, I>(&'a self, bytes: I) -> impl Iterator<Item = (usize, usize, T)> + 'a
+    where
+        I: IntoIterator<Item = u8>,
+        I::IntoIter: 'a,
+    {
+        bytes
+            .into_iter()
+            .scan(0, |state, byte| {
+                *state = self.transitions[*state][byte as usize] as usize;
+                Some(*state)
+            })
+            .enumerate()
+            .flat_map(|(i, state)| {
+                self.matches[state]
+                    .iter()
+                    .map(move |&(length, value)| (i + 1, length, value))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_overlapping_words() {
+        let automaton = Automaton::new([
+            (&b"he"[..], 'a'),
+            (b"she", 'b'),
+            (b"his", 'c'),
+            (b"hers", 'd'),
+        ]);
+
+        assert_eq!(
+            automaton.find_iter(*b"ushers").collect::<Vec<_>>(),
+            [(4, 3, 'b'), (4, 2, 'a'), (6, 4, 'd')]
+        );
+        assert_eq!(automaton.longest(), 4);
+    }
+}
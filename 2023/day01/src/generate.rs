@@ -0,0 +1,38 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day01;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// `size` is the number of lines. Every line has at least one digit, so that part 1
+// always has something to find
+impl Generate for Day01 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let mut pieces = (0..rng.gen_range(1..8))
+                .map(|_| match rng.gen_range(0..3) {
+                    0 => WORDS.choose(rng).unwrap().to_string(),
+                    1 => rng.gen_range(1..=9).to_string(),
+                    _ => (0..rng.gen_range(1..5))
+                        .map(|_| rng.gen_range('a'..='z'))
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+
+            let at = rng.gen_range(0..=pieces.len());
+            pieces.insert(at, rng.gen_range(1..=9).to_string());
+
+            input.push_str(&pieces.concat());
+            input.push('\n');
+        }
+
+        input
+    }
+}
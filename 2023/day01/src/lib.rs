@@ -0,0 +1,383 @@
+mod automaton;
+mod generate;
+#[cfg(test)]
+mod reference;
+mod report;
+
+use std::{cmp::Reverse, ops::Range, sync::LazyLock};
+
+use aoc_common::{
+    parse::{self, ParseError},
+    solution::{Answer, Part, Solution},
+    trace,
+};
+
+use crate::automaton::Automaton;
+pub use crate::report::report;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'input> = Vec<&'input str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !c.is_alphanumeric()) {
+                    return Err(line.error(
+                        &line.text[i..i + c.len_utf8()],
+                        "expected a letter or a digit",
+                    ));
+                }
+
+                Ok(line.text)
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Answer {
+        solve(lines, vocabulary(Part::One)).map_or(Answer::Unavailable, Answer::from)
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> Answer {
+        solve(lines, vocabulary(Part::Two)).map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+const DIGIT_WORDS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const NUMBER_WORDS: [(&str, u32); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+static DIGITS: LazyLock<Vocabulary> = LazyLock::new(|| Vocabulary::new(&DIGIT_WORDS));
+static DIGITS_AND_WORDS: LazyLock<Vocabulary> =
+    LazyLock::new(|| Vocabulary::new(&[DIGIT_WORDS, NUMBER_WORDS].concat()));
+
+// The words each part looks for
+pub fn vocabulary(part: Part) -> &'static Vocabulary {
+    match part {
+        Part::One => &DIGITS,
+        Part::Two => &DIGITS_AND_WORDS,
+    }
+}
+
+// The sum of every line's calibration value, or `None` if a line has no digits at all
+pub fn solve(lines: &[&str], strategy: &impl Day01Strategy) -> Option<u32> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let Some((first, last)) = strategy.first_and_last(line) else {
+                trace!("no digits", number = i + 1);
+                return None;
+            };
+
+            let value = calibration_value(&first, &last);
+
+            trace!(
+                "line",
+                number = i + 1,
+                first = first.digit,
+                first_span = first.span,
+                last = last.digit,
+                last_span = last.span,
+                value = value
+            );
+            Some(value)
+        })
+        .sum()
+}
+
+pub fn calibration_value(first: &Occurrence, last: &Occurrence) -> u32 {
+    first.digit * 10 + last.digit
+}
+
+// A digit or a word standing for one, found at `span` (in bytes) in a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    pub span: Range<usize>,
+    pub digit: u32,
+}
+
+pub trait Day01Strategy {
+    // Every digit in the line, in order of where they start; where several start at
+    // the same place, the longest comes first
+    fn occurrences(&self, line: &str) -> Vec<Occurrence>;
+
+    // The first and last digits in the line, which may be one and the same
+    fn first_and_last(&self, line: &str) -> Option<(Occurrence, Occurrence)> {
+        let occurrences = self.occurrences(line);
+        let first = occurrences.first()?;
+        let last_start = occurrences.last()?.span.start;
+        let last = occurrences
+            .iter()
+            .find(|occurrence| occurrence.span.start == last_start)?;
+
+        Some((first.clone(), last.clone()))
+    }
+}
+
+// The words that stand for each digit, which don't have to be in English. Where words
+// overlap, as in `eightwo`, the first digit is the word that starts first and the last
+// is the word that starts last
+pub struct Vocabulary {
+    forwards: Automaton<u32>,
+    // Finds the words in the line read back to front, so that the first one found
+    // is the last one in the line
+    backwards: Automaton<u32>,
+}
+
+impl Vocabulary {
+    pub fn new(words: &[(&str, u32)]) -> Self {
+        let reversed = words
+            .iter()
+            .map(|(word, digit)| (word.bytes().rev().collect::<Vec<_>>(), *digit))
+            .collect::<Vec<_>>();
+
+        Self {
+            forwards: Automaton::new(words.iter().map(|(word, digit)| (word.as_bytes(), *digit))),
+            backwards: Automaton::new(reversed.iter().map(|(word, digit)| (&word[..], *digit))),
+        }
+    }
+
+    // One word and its digit per line, e.g. `deux 2`
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let words = parse::lines(input)
+            .map(|line| {
+                let (word, digit) = line.split_once(line.text, " ")?;
+
+                if word.is_empty() {
+                    return Err(line.error(word, "expected a word"));
+                }
+
+                match line.parse(digit, "a digit")? {
+                    digit @ 0..=9 => Ok((word, digit)),
+                    _ => Err(line.error(digit, "expected a digit from 0 to 9")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(&words))
+    }
+
+    fn first(&self, line: &str) -> Option<Occurrence> {
+        let longest = self.forwards.longest();
+        let mut first: Option<Occurrence> = None;
+
+        for (end, length, digit) in self.forwards.find_iter(line.bytes()) {
+            let start = end - length;
+
+            if let Some(first) = &first {
+                // Nothing ending any later can start before the word found already
+                if end > first.span.start + longest {
+                    break;
+                }
+
+                // Of two words starting together, the longer one ends later
+                if start > first.span.start {
+                    continue;
+                }
+            }
+
+            first = Some(Occurrence {
+                span: start..end,
+                digit,
+            });
+        }
+
+        first
+    }
+
+    fn last(&self, line: &str) -> Option<Occurrence> {
+        self.backwards
+            .find_iter(line.bytes().rev())
+            .next()
+            .map(|(end, length, digit)| {
+                let start = line.len() - end;
+
+                Occurrence {
+                    span: start..start + length,
+                    digit,
+                }
+            })
+    }
+}
+
+impl Day01Strategy for Vocabulary {
+    fn occurrences(&self, line: &str) -> Vec<Occurrence> {
+        let mut occurrences = self
+            .forwards
+            .find_iter(line.bytes())
+            .map(|(end, length, digit)| Occurrence {
+                span: end - length..end,
+                digit,
+            })
+            .collect::<Vec<_>>();
+
+        occurrences.sort_by_key(|occurrence| (occurrence.span.start, Reverse(occurrence.span.end)));
+        occurrences
+    }
+
+    // Without finding every occurrence on the way
+    fn first_and_last(&self, line: &str) -> Option<(Occurrence, Occurrence)> {
+        Some((self.first(line)?, self.last(line)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    const INPUT_TEST_WORDS: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day01::solve_part1(INPUT_TEST).unwrap(), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day01::solve_part2(INPUT_TEST_WORDS).unwrap(), 281);
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let digits = |vocabulary: &Vocabulary, line| {
+            vocabulary
+                .first_and_last(line)
+                .map(|(first, last)| (first.digit, last.digit))
+        };
+
+        assert_eq!(digits(&DIGITS_AND_WORDS, "eightwo"), Some((8, 2)));
+        assert_eq!(digits(&DIGITS_AND_WORDS, "xoneightx"), Some((1, 8)));
+        assert_eq!(digits(&DIGITS, "eightwo"), None);
+
+        // The word that starts first wins even when another ends before it
+        let vocabulary = Vocabulary::new(&[("abcd", 1), ("bc", 2), ("cde", 3), ("d", 4)]);
+        assert_eq!(digits(&vocabulary, "abcde"), Some((1, 4)));
+        assert_eq!(digits(&vocabulary, "xbcde"), Some((2, 4)));
+        assert_eq!(digits(&vocabulary, "bcdx"), Some((2, 4)));
+    }
+
+    #[test]
+    fn other_languages() {
+        let french = Vocabulary::parse("un 1\ndeux 2\ntrois 3\nquatre 4\ncinq 5\n7 7\n").unwrap();
+        let german = Vocabulary::parse("eins 1\nzwei 2\ndrei 3\nfünf 5\nsieben 7\n").unwrap();
+
+        assert_eq!(
+            solve(&["xdeuxtroisy", "cinq7", "quatreun"], &french),
+            Some(23 + 57 + 41)
+        );
+        assert_eq!(
+            solve(&["fünfzweiß", "siebeneins", "dreinull"], &german),
+            Some(52 + 71 + 33)
+        );
+        assert_eq!(solve(&["eins", "null"], &german), None);
+    }
+
+    #[test]
+    fn finds_every_occurrence() {
+        let spans = |line| {
+            DIGITS_AND_WORDS
+                .occurrences(line)
+                .into_iter()
+                .map(|Occurrence { span, digit }| (span, digit))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            spans("xtwone3four"),
+            [(1..4, 2), (3..6, 1), (6..7, 3), (7..11, 4)]
+        );
+        assert_eq!(spans("seveneight"), [(0..5, 7), (5..10, 8)]);
+        assert!(spans("abc").is_empty());
+
+        // Both ways of finding the first and last agree
+        for line in INPUT_TEST_WORDS.lines().chain(["eightwo", "1", "oneight"]) {
+            let occurrences = DIGITS_AND_WORDS.occurrences(line);
+            let (first, last) = DIGITS_AND_WORDS.first_and_last(line).unwrap();
+
+            assert_eq!(first, occurrences[0]);
+            assert_eq!(last, *occurrences.last().unwrap());
+        }
+    }
+
+    #[test]
+    fn reports_what_was_matched() {
+        let lines = ["eightwothree", "7", "eightwo", "abc"];
+
+        assert_eq!(
+            report(&lines, vocabulary(Part::Two)),
+            "    \
+    1   83  [eight]wo(three)  eight=8 at 0..5, two=2 at 4..7, three=3 at 7..12
+    2   77  [7]               7=7 at 0..1
+    3   82  [eigh(t]wo)       eight=8 at 0..5, two=2 at 4..7
+    4    -  abc               no digits
+
+Sum: n/a, as some lines have no digits
+"
+        );
+        assert!(report(&["a1b2", "seven7"], vocabulary(Part::One)).ends_with("\nSum: 89\n"));
+    }
+
+    #[test]
+    fn reports_malformed_vocabularies() {
+        assert_eq!(Vocabulary::parse("un 1\ndeux\n").err().unwrap().line, 2);
+        assert_eq!(Vocabulary::parse("dix 10\n").err().unwrap().column, 5);
+        assert!(Vocabulary::parse(" 1\n").is_err());
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day01>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day01>(50);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day01>(0..20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
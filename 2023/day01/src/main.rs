@@ -0,0 +1,113 @@
+use std::{env, fs, process};
+
+use advent_of_code_2023_day01::{report, solve, vocabulary, Day01, Day01Strategy, Vocabulary};
+use aoc_common::{
+    input::{Args, ArgsError},
+    parse,
+    solution::{Part, Solution},
+};
+
+const REPORT_USAGE: &str = "\
+Usage: advent-of-code-2023-day01 report [INPUT] [--part <1|2>]
+
+Shows the digits found on each line, marking the first with [] and the last with ()";
+
+const WORDS_USAGE: &str = "\
+Usage: advent-of-code-2023-day01 words <PATH> [INPUT]
+
+Prints the sum of the calibration values, finding digits with the words in PATH instead:
+one word and the digit it stands for per line, e.g. `deux 2`";
+
+fn main() {
+    match env::args().nth(1).as_deref() {
+        Some("report") => report_main(),
+        Some("words") => words_main(),
+        _ => aoc_common::solution::main::<Day01>(env!("CARGO_MANIFEST_DIR")),
+    }
+}
+
+// Prints a report for each part, instead of just the answers
+fn report_main() {
+    let args = match Args::parse_only(env!("CARGO_MANIFEST_DIR"), env::args().skip(2), &["--part"])
+    {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{REPORT_USAGE}");
+            return;
+        }
+        Err(error) => usage_error(&error, REPORT_USAGE),
+    };
+
+    let input = args.input();
+    let input = parse::normalise(&input);
+    let lines = Day01::parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&args.source().name(), &input));
+        process::exit(1);
+    });
+
+    for part in Part::ALL.into_iter().filter(|part| args.runs_part(*part)) {
+        println!("Part {part}\n");
+        println!("{}", report(&lines, vocabulary(part)));
+    }
+}
+
+// Solves with a vocabulary of one's own rather than either part's
+fn words_main() {
+    let mut args = env::args().skip(2);
+
+    let words_path = match args.next() {
+        Some(path) if path == "-h" || path == "--help" => {
+            println!("{WORDS_USAGE}");
+            return;
+        }
+        Some(path) if !path.starts_with('-') => path,
+        Some(arg) => usage_error(&ArgsError::UnexpectedArgument(arg), WORDS_USAGE),
+        None => usage_error(&ArgsError::MissingValue("words"), WORDS_USAGE),
+    };
+
+    let args = match Args::parse_only(env!("CARGO_MANIFEST_DIR"), args, &[]) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{WORDS_USAGE}");
+            return;
+        }
+        Err(error) => usage_error(&error, WORDS_USAGE),
+    };
+
+    let words = fs::read_to_string(&words_path).unwrap_or_else(|error| {
+        eprintln!("error: could not read words file `{words_path}`: {error}");
+        process::exit(1);
+    });
+    let words = parse::normalise(&words);
+    let vocabulary = Vocabulary::parse(&words).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&words_path, &words));
+        process::exit(1);
+    });
+
+    let input = args.input();
+    let input = parse::normalise(&input);
+    let lines = Day01::parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&args.source().name(), &input));
+        process::exit(1);
+    });
+
+    match solve(&lines, &vocabulary) {
+        Some(sum) => println!("{sum}"),
+        None => {
+            let i = lines
+                .iter()
+                .position(|line| vocabulary.first_and_last(line).is_none())
+                .unwrap();
+            eprintln!(
+                "error: line {} has none of the words in `{words_path}`",
+                i + 1
+            );
+            process::exit(1);
+        }
+    }
+}
+
+fn usage_error(error: &ArgsError, usage: &str) -> ! {
+    eprintln!("error: {error}\n\n{usage}");
+    process::exit(2);
+}
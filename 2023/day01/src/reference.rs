@@ -0,0 +1,40 @@
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day01;
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// Tries every word at every position in the line, from each end in turn
+impl Reference for Day01 {
+    const REFERENCE_SIZE: usize = 50;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        let digit_at = |line: &str, at: usize| {
+            let rest = &line[at..];
+
+            (0..10).find(|&digit| {
+                rest.starts_with(char::from(b'0' + digit as u8))
+                    || (part == Part::Two && rest.starts_with(WORDS[digit]))
+            })
+        };
+
+        input
+            .lines()
+            .map(|line| {
+                let first = (0..line.len()).find_map(|at| digit_at(line, at)).unwrap();
+                let last = (0..line.len())
+                    .rev()
+                    .find_map(|at| digit_at(line, at))
+                    .unwrap();
+
+                first * 10 + last
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
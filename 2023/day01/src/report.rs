@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use crate::{calibration_value, Day01Strategy, Occurrence};
+
+// Shows what was matched on each line, for tracking down a wrong sum. The first digit
+// is marked with `[]` and the last with `()`, and every digit found follows:
+//
+//      2   83  [eight]wo(three)  eight=8 at 0..5, two=2 at 4..7, three=3 at 7..12
+pub fn report(lines: &[&str], strategy: &impl Day01Strategy) -> String {
+    let rows = lines
+        .iter()
+        .map(|line| {
+            let occurrences = strategy.occurrences(line);
+
+            match strategy.first_and_last(line) {
+                Some((first, last)) => (
+                    calibration_value(&first, &last).to_string(),
+                    mark(line, &first, &last),
+                    list(line, &occurrences),
+                ),
+                None => ("-".to_string(), line.to_string(), "no digits".to_string()),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let width = rows
+        .iter()
+        .map(|(_, marked, _)| marked.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut report = String::new();
+
+    for (i, (value, marked, found)) in rows.iter().enumerate() {
+        let padding = " ".repeat(width - marked.chars().count());
+        writeln!(
+            report,
+            "{:>5}  {value:>3}  {marked}{padding}  {found}",
+            i + 1
+        )
+        .unwrap();
+    }
+
+    let sum = rows
+        .iter()
+        .map(|(value, _, _)| value.parse::<u32>().ok())
+        .sum::<Option<u32>>();
+
+    match sum {
+        Some(sum) => writeln!(report, "\nSum: {sum}").unwrap(),
+        None => writeln!(report, "\nSum: n/a, as some lines have no digits").unwrap(),
+    }
+
+    report
+}
+
+// Where first and last overlap, as in `[eigh(t]wo)`, the brackets do too
+fn mark(line: &str, first: &Occurrence, last: &Occurrence) -> String {
+    let mut marks = vec![(first.span.start, '['), (first.span.end, ']')];
+
+    if last != first {
+        marks.extend([(last.span.start, '('), (last.span.end, ')')]);
+    }
+
+    // Closing before opening where they meet, so that `[1](2)` doesn't become `[1(]2)`
+    marks.sort_by_key(|&(at, mark)| (at, matches!(mark, '[' | '(')));
+
+    let mut marked = String::new();
+    let mut from = 0;
+
+    for (at, mark) in marks {
+        marked.push_str(&line[from..at]);
+        marked.push(mark);
+        from = at;
+    }
+
+    marked.push_str(&line[from..]);
+    marked
+}
+
+fn list(line: &str, occurrences: &[Occurrence]) -> String {
+    occurrences
+        .iter()
+        .map(|Occurrence { span, digit }| {
+            format!(
+                "{}={digit} at {}..{}",
+                &line[span.clone()],
+                span.start,
+                span.end
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,32 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day02;
+
+// `size` is the number of games
+impl Generate for Day02 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        for id in 1..=size {
+            let reveals = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    colours.shuffle(rng);
+
+                    colours[..rng.gen_range(1..=3)]
+                        .iter()
+                        .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+
+            input.push_str(&format!("Game {id}: {}\n", reveals.join("; ")));
+        }
+
+        input
+    }
+}
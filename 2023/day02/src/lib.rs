@@ -0,0 +1,337 @@
+mod generate;
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed<'input> = Vec<Game<'input>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input).map(Game::try_from).collect()
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> Answer {
+        let bag = Cubes::from([(Colour::Red, 12), (Colour::Green, 13), (Colour::Blue, 14)]);
+
+        games
+            .iter()
+            .filter(|game| {
+                let possible = game.is_possible_with(&bag);
+                trace!("game", id = game.id, possible = possible);
+                possible
+            })
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> Answer {
+        games
+            .iter()
+            .map(|game| {
+                let minimum = game.minimum_bag();
+                let power = power(&minimum);
+
+                trace!(
+                    "game",
+                    id = game.id,
+                    minimum = sorted(&minimum),
+                    power = power
+                );
+                power
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Colour<'a> {
+    Red,
+    Green,
+    Blue,
+    Other(&'a str),
+}
+
+impl<'a> From<&'a str> for Colour<'a> {
+    fn from(name: &'a str) -> Self {
+        match name {
+            "red" => Self::Red,
+            "green" => Self::Green,
+            "blue" => Self::Blue,
+            name => Self::Other(name),
+        }
+    }
+}
+
+impl Display for Colour<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Red => write!(f, "red"),
+            Self::Green => write!(f, "green"),
+            Self::Blue => write!(f, "blue"),
+            Self::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+// How many cubes of each colour there are, in a reveal or in a bag
+pub type Cubes<'a> = HashMap<Colour<'a>, u32>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub reveals: Vec<Cubes<'a>>,
+}
+
+impl<'a> TryFrom<Line<'a>> for Game<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'a>) -> Result<Self, Self::Error> {
+        let (id, reveals) = line.header("Game", "a game id")?;
+
+        let reveals = reveals
+            .split(';')
+            .map(|reveal| parse_cubes(&line, reveal))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { id, reveals })
+    }
+}
+
+impl<'a> Game<'a> {
+    // Whether every reveal could have come out of `bag`. A colour missing from the bag
+    // has no cubes in it
+    pub fn is_possible_with(&self, bag: &Cubes<'_>) -> bool {
+        self.reveals.iter().all(|reveal| {
+            reveal
+                .iter()
+                .all(|(colour, count)| count <= bag.get(colour).unwrap_or(&0))
+        })
+    }
+
+    // The fewest cubes of each colour that the bag could have held
+    pub fn minimum_bag(&self) -> Cubes<'a> {
+        let mut bag = Cubes::new();
+
+        for (colour, &count) in self.reveals.iter().flatten() {
+            let most = bag.entry(*colour).or_insert(0);
+            *most = (*most).max(count);
+        }
+
+        bag
+    }
+}
+
+// Colours the bag doesn't mention don't count towards its power
+pub fn power(bag: &Cubes<'_>) -> u32 {
+    bag.values().product()
+}
+
+// In colour order, so that output doesn't depend on how the colours were hashed
+fn sorted<'a>(cubes: &Cubes<'a>) -> Vec<(Colour<'a>, u32)> {
+    let mut cubes = cubes
+        .iter()
+        .map(|(&colour, &n)| (colour, n))
+        .collect::<Vec<_>>();
+    cubes.sort_unstable();
+    cubes
+}
+
+// As `parse_bag` reads them, e.g. `12 red, 13 green, 14 blue`
+pub fn describe(cubes: &Cubes<'_>) -> String {
+    sorted(cubes)
+        .iter()
+        .map(|(colour, count)| format!("{count} {colour}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Every bag with at least `minimum` cubes of each colour: exactly the bags that make
+// all of some set of games possible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bags<'a> {
+    pub minimum: Cubes<'a>,
+}
+
+impl<'a> Bags<'a> {
+    pub fn for_games<'g>(games: impl IntoIterator<Item = &'g Game<'a>>) -> Self
+    where
+        'a: 'g,
+    {
+        let mut minimum = Cubes::new();
+
+        for game in games {
+            for (colour, count) in game.minimum_bag() {
+                let most = minimum.entry(colour).or_insert(0);
+                *most = (*most).max(count);
+            }
+        }
+
+        Self { minimum }
+    }
+
+    pub fn contains(&self, bag: &Cubes<'_>) -> bool {
+        self.minimum
+            .iter()
+            .all(|(colour, count)| count <= bag.get(colour).unwrap_or(&0))
+    }
+}
+
+// A bag given as a reveal would be, e.g. `12 red, 13 green, 14 blue`
+pub fn parse_bag(text: &str) -> Result<Cubes<'_>, ParseError> {
+    let mut lines = parse::lines(text);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(text, "expected a bag"))?;
+
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "expected the bag on a single line"));
+    }
+
+    parse_cubes(&line, line.text)
+}
+
+// `3 blue, 4 red`, where each colour appears at most once
+fn parse_cubes<'a>(line: &Line<'a>, text: &'a str) -> Result<Cubes<'a>, ParseError> {
+    let mut cubes = Cubes::new();
+
+    for readout in text.split(',') {
+        let (count, colour) = line.split_once(readout.trim(), " ")?;
+        let colour = colour.trim();
+
+        if colour.is_empty() {
+            return Err(line.error(colour, "expected a colour"));
+        }
+
+        let count = line.parse(count, "a number of cubes")?;
+
+        if cubes.insert(Colour::from(colour), count).is_some() {
+            return Err(line.error(colour, "expected each colour at most once"));
+        }
+    }
+
+    Ok(cubes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day02::solve_part1(INPUT_TEST).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day02::solve_part2(INPUT_TEST).unwrap(), 2286);
+    }
+
+    #[test]
+    fn bags_given_at_runtime() {
+        let games = Day02::parse(INPUT_TEST).unwrap();
+        let possible = |bag| {
+            let bag = parse_bag(bag).unwrap();
+
+            games
+                .iter()
+                .filter(|game| game.is_possible_with(&bag))
+                .map(|game| game.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(possible("12 red, 13 green, 14 blue"), [1, 2, 5]);
+        assert_eq!(possible("20 red, 13 green, 15 blue"), [1, 2, 3, 4, 5]);
+        assert_eq!(possible("4 red, 2 green, 6 blue"), [1]);
+        assert_eq!(possible("100 red, 100 green"), []);
+    }
+
+    #[test]
+    fn bags_for_some_games() {
+        let games = Day02::parse(INPUT_TEST).unwrap();
+        let bags = Bags::for_games([&games[0], &games[2]]);
+
+        assert_eq!(
+            bags.minimum,
+            Cubes::from([(Colour::Red, 20), (Colour::Green, 13), (Colour::Blue, 6)])
+        );
+
+        for bag in [
+            "20 red, 13 green, 6 blue",
+            "21 red, 13 green, 6 blue, 1 pink",
+        ] {
+            let bag = parse_bag(bag).unwrap();
+
+            assert!(bags.contains(&bag));
+            assert!(games[0].is_possible_with(&bag) && games[2].is_possible_with(&bag));
+        }
+
+        let bag = parse_bag("20 red, 12 green, 6 blue").unwrap();
+        assert!(!bags.contains(&bag));
+        assert!(!games[2].is_possible_with(&bag));
+    }
+
+    #[test]
+    fn bags_are_described_as_they_are_parsed() {
+        let bag = parse_bag("3 pink, 14 blue, 12 red").unwrap();
+
+        assert_eq!(describe(&bag), "12 red, 14 blue, 3 pink");
+        assert_eq!(parse_bag(&describe(&bag)).unwrap(), bag);
+    }
+
+    #[test]
+    fn other_colours() {
+        let games = Day02::parse("Game 7: 2 pink, 1 red; 5 pink\nGame 8: 1 teal\n").unwrap();
+
+        assert_eq!(
+            games[0].minimum_bag(),
+            Cubes::from([(Colour::Other("pink"), 5), (Colour::Red, 1)])
+        );
+        assert_eq!(power(&games[0].minimum_bag()), 5);
+
+        let bag = parse_bag("5 pink, 1 red").unwrap();
+        assert!(games[0].is_possible_with(&bag));
+        assert!(!games[1].is_possible_with(&bag));
+    }
+
+    #[test]
+    fn reports_malformed_reveals() {
+        let error = Day02::parse("Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 red\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 18));
+
+        assert!(parse_bag("12 red\n13 green\n").is_err());
+        assert!(parse_bag("12").is_err());
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day02>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day02>(50);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
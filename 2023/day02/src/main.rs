@@ -0,0 +1,143 @@
+use std::{env, process};
+
+use advent_of_code_2023_day02::{describe, parse_bag, power, Bags, Day02};
+use aoc_common::{
+    input::{Args, ArgsError},
+    parse,
+    solution::Solution,
+};
+
+const BAGS_USAGE: &str = "\
+Usage: advent-of-code-2023-day02 bags [INPUT] (--bag <CUBES> | --minimum | --games <IDS>)
+
+Options:
+  --bag <CUBES>  List the games possible with the bag, e.g. `--bag \"12 red, 13 green, 14 blue\"`,
+                 as in part 1
+  --minimum      Show the smallest bag for each game, and its power, as in part 2
+  --games <IDS>  Show the smallest bag that makes every one of the games possible,
+                 e.g. `--games 1,3`; any bag with at least as many of each colour will do";
+
+enum Query {
+    Bag(String),
+    Minimum,
+    Games(String),
+}
+
+fn main() {
+    if env::args().nth(1).as_deref() == Some("bags") {
+        bags_main();
+    } else {
+        aoc_common::solution::main::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+// Asks about bags other than the puzzle's own
+fn bags_main() {
+    let mut query = None;
+    let mut rest = Vec::new();
+    let mut args = env::args().skip(2);
+
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--bag" => Query::Bag(
+                args.next()
+                    .unwrap_or_else(|| usage_error(&ArgsError::MissingValue("--bag"))),
+            ),
+            "--minimum" => Query::Minimum,
+            "--games" => Query::Games(
+                args.next()
+                    .unwrap_or_else(|| usage_error(&ArgsError::MissingValue("--games"))),
+            ),
+            _ => {
+                rest.push(arg);
+                continue;
+            }
+        };
+
+        // Only one question at a time
+        if query.replace(next).is_some() {
+            usage_error(&ArgsError::UnexpectedArgument(arg));
+        }
+    }
+
+    let args = match Args::parse_only(env!("CARGO_MANIFEST_DIR"), rest, &[]) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{BAGS_USAGE}");
+            return;
+        }
+        Err(error) => usage_error(&error),
+    };
+
+    let Some(query) = query else {
+        eprintln!("error: expected one of `--bag`, `--minimum` or `--games`\n\n{BAGS_USAGE}");
+        process::exit(2);
+    };
+
+    let input = args.input();
+    let input = parse::normalise(&input);
+    let games = Day02::parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&args.source().name(), &input));
+        process::exit(1);
+    });
+
+    match query {
+        Query::Bag(bag) => {
+            let bag = parse_bag(&bag).unwrap_or_else(|error| {
+                eprintln!("{}", error.render("--bag", &bag));
+                process::exit(1);
+            });
+            let possible = games
+                .iter()
+                .filter(|game| game.is_possible_with(&bag))
+                .map(|game| game.id)
+                .collect::<Vec<_>>();
+
+            for id in &possible {
+                println!("Game {id}");
+            }
+
+            println!("\nSum of ids: {}", possible.iter().sum::<u32>());
+        }
+        Query::Minimum => {
+            for game in &games {
+                let minimum = game.minimum_bag();
+                println!(
+                    "Game {}: {} (power {})",
+                    game.id,
+                    describe(&minimum),
+                    power(&minimum)
+                );
+            }
+
+            let total = games
+                .iter()
+                .map(|game| power(&game.minimum_bag()))
+                .sum::<u32>();
+            println!("\nSum of powers: {total}");
+        }
+        Query::Games(ids) => {
+            let chosen = ids
+                .split(',')
+                .map(|id| {
+                    let id = id.trim();
+
+                    id.parse()
+                        .ok()
+                        .and_then(|id| games.iter().find(|game| game.id == id))
+                        .unwrap_or_else(|| {
+                            eprintln!("error: no game with id `{id}`");
+                            process::exit(1);
+                        })
+                })
+                .collect::<Vec<_>>();
+
+            println!("At least {}", describe(&Bags::for_games(chosen).minimum));
+        }
+    }
+}
+
+fn usage_error(error: &ArgsError) -> ! {
+    eprintln!("error: {error}\n\n{BAGS_USAGE}");
+    process::exit(2);
+}
@@ -0,0 +1,48 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day03;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+
+// `size` is the side of the schematic
+impl Generate for Day03 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut rows = generate::grid(rng, size, size, |rng, _, _| {
+            if rng.gen_ratio(1, 12) {
+                SYMBOLS[rng.gen_range(0..SYMBOLS.len())]
+            } else {
+                '.'
+            }
+        })
+        .lines()
+        .map(|row| row.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+        // Numbers of up to three digits, each with room either side of it
+        for row in &mut rows {
+            let mut x = 0;
+
+            while x + 4 < size {
+                if rng.gen_ratio(1, 6) {
+                    let number = rng.gen_range(1..1000).to_string();
+
+                    row[x] = '.';
+                    row[x + 1..x + 1 + number.len()]
+                        .copy_from_slice(&number.chars().collect::<Vec<_>>());
+                    row[x + 1 + number.len()] = '.';
+
+                    x += number.len() + 2;
+                } else {
+                    x += 1;
+                }
+            }
+        }
+
+        rows.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
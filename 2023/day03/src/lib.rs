@@ -0,0 +1,313 @@
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_common::{
+    geometry::Position,
+    grid::Grid,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'input> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Schematic::try_from(input)
+    }
+
+    fn part1(schematic: &Self::Parsed<'_>) -> Answer {
+        schematic
+            .part_numbers()
+            .map(|number| {
+                trace!(
+                    "part number",
+                    value = number.value,
+                    at = (number.position.x, number.position.y)
+                );
+                number.value
+            })
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(schematic: &Self::Parsed<'_>) -> Answer {
+        schematic.gear_ratios(GEARS).sum::<u64>().into()
+    }
+}
+
+// A gear is a `*` next to exactly two numbers
+pub const GEARS: Gears = Gears {
+    symbol: '*',
+    arity: 2,
+};
+
+// Which symbols count as gears: those shown as `symbol` with exactly `arity` numbers
+// next to them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gears {
+    pub symbol: char,
+    pub arity: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u64,
+    // Of the first digit
+    pub position: Position,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub position: Position,
+}
+
+// The numbers and symbols in the schematic, and which are next to which, including
+// diagonally. Numbers and symbols are referred to by their index
+#[derive(Debug)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    symbols_by_number: Vec<Vec<usize>>,
+    numbers_by_symbol: Vec<Vec<usize>>,
+}
+
+impl TryFrom<&str> for Schematic {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // Only checking the shape and contents here
+        Grid::try_parse(input, "a digit, `.` or a symbol", |c| {
+            c.is_ascii_graphic().then_some(())
+        })?;
+
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+
+        for (y, line) in parse::lines(input).enumerate() {
+            let mut x = 0;
+
+            while let Some(c) = line.text[x..].chars().next() {
+                if c.is_ascii_digit() {
+                    let length = line.text[x..]
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(line.text.len() - x);
+
+                    numbers.push(Number {
+                        value: line.parse(&line.text[x..x + length], "a part number")?,
+                        position: Position::from((x, y)),
+                        length,
+                    });
+
+                    x += length;
+                    continue;
+                }
+
+                if c != '.' {
+                    symbols.push(Symbol {
+                        symbol: c,
+                        position: Position::from((x, y)),
+                    });
+                }
+
+                x += c.len_utf8();
+            }
+        }
+
+        Ok(Self::new(numbers, symbols))
+    }
+}
+
+impl Schematic {
+    pub fn new(numbers: Vec<Number>, symbols: Vec<Symbol>) -> Self {
+        let symbol_at = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| (symbol.position, i))
+            .collect::<HashMap<_, _>>();
+
+        let mut symbols_by_number = vec![Vec::new(); numbers.len()];
+        let mut numbers_by_symbol = vec![Vec::new(); symbols.len()];
+
+        for (i, number) in numbers.iter().enumerate() {
+            let Position { x, y } = number.position;
+
+            // The ring of cells around the number, which may be outside the schematic
+            for around_y in y - 1..=y + 1 {
+                for around_x in x - 1..=x + number.length as i64 {
+                    let around = Position {
+                        x: around_x,
+                        y: around_y,
+                    };
+
+                    if let Some(&symbol) = symbol_at.get(&around) {
+                        symbols_by_number[i].push(symbol);
+                        numbers_by_symbol[symbol].push(i);
+                    }
+                }
+            }
+        }
+
+        Self {
+            numbers,
+            symbols,
+            symbols_by_number,
+            numbers_by_symbol,
+        }
+    }
+
+    // The numbers next to at least one symbol
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .zip(&self.symbols_by_number)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number)
+    }
+
+    pub fn symbols_next_to(&self, number: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbols_by_number[number]
+            .iter()
+            .map(|&symbol| &self.symbols[symbol])
+    }
+
+    pub fn numbers_next_to(&self, symbol: usize) -> impl Iterator<Item = &Number> {
+        self.numbers_by_symbol[symbol]
+            .iter()
+            .map(|&number| &self.numbers[number])
+    }
+
+    // The symbols shown as `symbol` (or any symbol, for `None`) with exactly `arity`
+    // numbers next to them
+    pub fn symbols_with_arity(
+        &self,
+        symbol: Option<char>,
+        arity: usize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len()).filter(move |&i| {
+            symbol.is_none_or(|symbol| self.symbols[i].symbol == symbol)
+                && self.numbers_by_symbol[i].len() == arity
+        })
+    }
+
+    // The product of the numbers next to the symbol
+    pub fn ratio(&self, symbol: usize) -> u64 {
+        self.numbers_next_to(symbol)
+            .map(|number| number.value)
+            .product()
+    }
+
+    pub fn gear_ratios(&self, gears: Gears) -> impl Iterator<Item = u64> + '_ {
+        self.symbols_with_arity(Some(gears.symbol), gears.arity)
+            .map(|symbol| {
+                let Position { x, y } = self.symbols[symbol].position;
+
+                trace!(
+                    "gear",
+                    at = (x, y),
+                    numbers = self
+                        .numbers_next_to(symbol)
+                        .map(|number| number.value)
+                        .collect::<Vec<_>>()
+                );
+                self.ratio(symbol)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day03::solve_part1(INPUT_TEST).unwrap(), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day03::solve_part2(INPUT_TEST).unwrap(), 467835);
+    }
+
+    #[test]
+    fn adjacency_goes_both_ways() {
+        let schematic = Schematic::try_from(INPUT_TEST).unwrap();
+
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+
+        // 114 and 58 are the only numbers not next to a symbol
+        assert_eq!(schematic.part_numbers().count(), 8);
+
+        let star = schematic
+            .symbols
+            .iter()
+            .position(|symbol| symbol.position == Position { x: 3, y: 1 })
+            .unwrap();
+        let next_to_star = schematic
+            .numbers_next_to(star)
+            .map(|number| number.value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(next_to_star, [467, 35]);
+        assert_eq!(schematic.ratio(star), 16345);
+
+        let first = schematic.symbols_next_to(0).collect::<Vec<_>>();
+        assert_eq!(first, [&schematic.symbols[star]]);
+    }
+
+    #[test]
+    fn other_kinds_of_gear() {
+        let schematic = Schematic::try_from(INPUT_TEST).unwrap();
+
+        // The `*` next to only 617, and every symbol next to just one number
+        let single_stars = schematic.gear_ratios(Gears {
+            symbol: '*',
+            arity: 1,
+        });
+        assert_eq!(single_stars.collect::<Vec<_>>(), [617]);
+        assert_eq!(schematic.symbols_with_arity(None, 1).count(), 4);
+
+        let schematic = Schematic::try_from("3.2.3\n.#.+.\n4.5.6\n").unwrap();
+        let hashes = schematic.gear_ratios(Gears {
+            symbol: '#',
+            arity: 4,
+        });
+
+        assert_eq!(hashes.collect::<Vec<_>>(), [3 * 2 * 4 * 5]);
+        assert_eq!(schematic.gear_ratios(GEARS).count(), 0);
+        assert_eq!(Day03::solve_part1("3.2.3\n.#.+.\n4.5.6\n").unwrap(), 23);
+    }
+
+    #[test]
+    fn reports_numbers_that_are_too_big() {
+        let error = Day03::parse("1.123456789012345678901234\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day03>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day03>(50);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
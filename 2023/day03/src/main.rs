@@ -0,0 +1,5 @@
+use advent_of_code_2023_day03::Day03;
+
+fn main() {
+    aoc_common::solution::main::<Day03>(env!("CARGO_MANIFEST_DIR"));
+}
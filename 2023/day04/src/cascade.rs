@@ -0,0 +1,144 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{self, Display, Formatter, Write},
+};
+
+use aoc_common::trace;
+
+use crate::{Card, CopyRule};
+
+// How many of each card there are once every copy has been scratched, and which cards
+// the copies were won from. Cards are referred to by index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    // Including the original
+    pub copies: Vec<u64>,
+    // The cards whose copies each won a copy of the card, once per win
+    pub won_by: Vec<Vec<usize>>,
+    // The cards each copy of the card wins a copy of
+    pub wins: Vec<Vec<usize>>,
+}
+
+// Copies of the card are won, directly or not, by copies of the card itself, so there
+// would be no end to them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndlessCascade {
+    pub card: usize,
+}
+
+impl Display for EndlessCascade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "card {} would be copied endlessly", self.card + 1)
+    }
+}
+
+impl std::error::Error for EndlessCascade {}
+
+impl Cascade {
+    pub fn new(cards: &[Card], rule: &impl CopyRule) -> Result<Self, EndlessCascade> {
+        let wins = cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                let mut won = rule.wins(i, card.matches(), cards.len());
+                won.retain(|&j| j < cards.len());
+                won
+            })
+            .collect::<Vec<_>>();
+
+        let mut won_by = vec![Vec::new(); cards.len()];
+
+        for (i, won) in wins.iter().enumerate() {
+            for &j in won {
+                won_by[j].push(i);
+            }
+        }
+
+        // A card's count is final once every card that wins it is, so the cards are
+        // settled lowest first among those whose winners all are
+        let mut unsettled = won_by.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut ready = (0..cards.len())
+            .filter(|&i| unsettled[i] == 0)
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut copies = vec![1_u64; cards.len()];
+        let mut settled = 0;
+
+        while let Some(Reverse(i)) = ready.pop() {
+            trace!(
+                "card",
+                number = i + 1,
+                copies = copies[i],
+                wins = wins[i].iter().map(|j| j + 1).collect::<Vec<_>>()
+            );
+            settled += 1;
+
+            for &j in &wins[i] {
+                copies[j] += copies[i];
+                unsettled[j] -= 1;
+
+                if unsettled[j] == 0 {
+                    ready.push(Reverse(j));
+                }
+            }
+        }
+
+        if settled < cards.len() {
+            let card = (0..cards.len()).find(|&i| unsettled[i] > 0).unwrap();
+            return Err(EndlessCascade { card });
+        }
+
+        Ok(Self {
+            copies,
+            won_by,
+            wins,
+        })
+    }
+
+    pub fn total(&self) -> u64 {
+        self.copies.iter().sum()
+    }
+
+    // Each card's count, broken down into the original and the copies won from each
+    // card that wins it. Those copies are in turn explained under the card they came from:
+    //
+    //     Card 4: 8 copies, each winning card 5
+    //       1 original
+    //       1 from card 1
+    //       2 from card 2
+    //       4 from card 3
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        for (i, copies) in self.copies.iter().enumerate() {
+            let plural = if *copies == 1 { "copy" } else { "copies" };
+            write!(report, "Card {}: {copies} {plural}", i + 1).unwrap();
+
+            if !self.wins[i].is_empty() {
+                let wins = self.wins[i]
+                    .iter()
+                    .map(|j| (j + 1).to_string())
+                    .collect::<Vec<_>>();
+                let cards = if wins.len() == 1 { "card" } else { "cards" };
+                write!(report, ", each winning {cards} {}", wins.join(", ")).unwrap();
+            }
+
+            writeln!(report, "\n  1 original").unwrap();
+
+            // A card winning this one more than once shows up once, with all its wins
+            let mut won_by = self.won_by[i].clone();
+            won_by.sort_unstable();
+            won_by.dedup();
+
+            for j in won_by {
+                let times = self.won_by[i].iter().filter(|&&k| k == j).count() as u64;
+                writeln!(report, "  {} from card {}", times * self.copies[j], j + 1).unwrap();
+            }
+        }
+
+        writeln!(report, "\nTotal: {}", self.total()).unwrap();
+
+        report
+    }
+}
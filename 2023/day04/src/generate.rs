@@ -0,0 +1,65 @@
+use aoc_common::generate::Generate;
+use rand::{
+    rngs::StdRng,
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+use crate::Day04;
+
+const WINNING: usize = 10;
+const OURS: usize = 25;
+
+// Copies multiply quickly, so matches are held back once there would be more cards than this
+const MAX_CARDS: usize = 100_000_000;
+
+// `size` is the number of cards. No card wins copies of cards past the last one, and the
+// total number of cards stays within `MAX_CARDS`
+impl Generate for Day04 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        let mut copies = vec![1; size];
+        let mut total = size;
+
+        for card in 1..=size {
+            let max_matches = WINNING
+                .min(size - card)
+                .min((MAX_CARDS - total) / copies[card - 1]);
+            let matches = rng.gen_range(0..=max_matches);
+
+            for i in card..card + matches {
+                copies[i] += copies[card - 1];
+            }
+            total += matches * copies[card - 1];
+
+            // The first `WINNING` numbers are the winning ones; ours share `matches` of them
+            let numbers = index::sample(rng, 99, WINNING + OURS - matches)
+                .into_iter()
+                .map(|i| i + 1)
+                .collect::<Vec<_>>();
+            let winning = &numbers[..WINNING];
+
+            let mut ours = [&winning[..matches], &numbers[WINNING..]].concat();
+            ours.shuffle(rng);
+
+            input.push_str(&format!(
+                "Card {card:>3}: {} | {}\n",
+                format(winning),
+                format(&ours)
+            ));
+        }
+
+        input
+    }
+}
+
+fn format(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{number:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,270 @@
+mod cascade;
+mod generate;
+mod numbers;
+
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub use crate::{
+    cascade::{Cascade, EndlessCascade},
+    numbers::NumberSet,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed<'input> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input).map(Card::try_from).collect()
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> Answer {
+        total_points(cards, &Doubling).map_or(Answer::Unavailable, Answer::from)
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> Answer {
+        Cascade::new(cards, &NextCards)
+            .expect("cards only win copies of later cards")
+            .total()
+            .into()
+    }
+}
+
+// `None` if there are too many points to count
+pub fn total_points(cards: &[Card], scoring: &impl Scoring) -> Option<u64> {
+    let mut sum = 0_u64;
+
+    for (i, card) in cards.iter().enumerate() {
+        let points = scoring.points(card.matches())?;
+
+        trace!(
+            "card",
+            number = i + 1,
+            winners = card.winners().collect::<Vec<_>>(),
+            points = points
+        );
+        sum = sum.checked_add(points)?;
+    }
+
+    Some(sum)
+}
+
+// How many points a card with some number of matches is worth, or `None` if that's too
+// many to count
+pub trait Scoring {
+    fn points(&self, matches: usize) -> Option<u64>;
+}
+
+// 1 point for the first match, then double for each after it
+pub struct Doubling;
+
+impl Scoring for Doubling {
+    fn points(&self, matches: usize) -> Option<u64> {
+        match matches {
+            0 => Some(0),
+            _ => 1_u64.checked_shl(u32::try_from(matches - 1).ok()?),
+        }
+    }
+}
+
+// 1 point per match
+pub struct Linear;
+
+impl Scoring for Linear {
+    fn points(&self, matches: usize) -> Option<u64> {
+        u64::try_from(matches).ok()
+    }
+}
+
+// Which cards each copy of a card wins a copy of, by index, given how many matches it
+// has and how many cards there are. Cards past the last one are ignored, and a card
+// listed twice is won twice
+pub trait CopyRule {
+    fn wins(&self, card: usize, matches: usize, cards: usize) -> Vec<usize>;
+}
+
+// A copy of each of the next `matches` cards
+pub struct NextCards;
+
+impl CopyRule for NextCards {
+    fn wins(&self, card: usize, matches: usize, cards: usize) -> Vec<usize> {
+        (card + 1..cards.min(card + 1 + matches)).collect()
+    }
+}
+
+// A copy of each of the previous `matches` cards, nearest first
+pub struct PreviousCards;
+
+impl CopyRule for PreviousCards {
+    fn wins(&self, card: usize, matches: usize, _: usize) -> Vec<usize> {
+        (card.saturating_sub(matches)..card).rev().collect()
+    }
+}
+
+// No more than `most` of the cards `rule` would give
+pub struct Capped<R> {
+    pub rule: R,
+    pub most: usize,
+}
+
+impl<R: CopyRule> CopyRule for Capped<R> {
+    fn wins(&self, card: usize, matches: usize, cards: usize) -> Vec<usize> {
+        let mut won = self.rule.wins(card, matches, cards);
+        won.truncate(self.most);
+        won
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub winning: NumberSet,
+    pub ours: NumberSet,
+}
+
+impl Card {
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.ours).len()
+    }
+
+    // In ascending order
+    pub fn winners(&self) -> impl Iterator<Item = u8> {
+        self.winning.intersection(&self.ours).iter()
+    }
+}
+
+impl TryFrom<Line<'_>> for Card {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let (_, numbers) = line.header::<u32>("Card", "a card number")?;
+        let (winning, ours) = line.split_once(numbers, "|")?;
+
+        let what = "a number from 0 to 255";
+        let winning = NumberSet::from_iter(line.parse_all::<u8>(winning, what)?);
+        let ours = NumberSet::from_iter(line.parse_all::<u8>(ours, what)?);
+
+        Ok(Self { winning, ours })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day04::solve_part1(INPUT_TEST).unwrap(), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day04::solve_part2(INPUT_TEST).unwrap(), 30);
+    }
+
+    #[test]
+    fn other_scoring() {
+        let cards = Day04::parse(INPUT_TEST).unwrap();
+
+        // 4, 2, 2, 1, 0 and 0 matches
+        assert_eq!(total_points(&cards, &Doubling), Some(13));
+        assert_eq!(total_points(&cards, &Linear), Some(9));
+    }
+
+    #[test]
+    fn too_many_matches_to_count_have_no_answer() {
+        let numbers = (0..65).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+        let input = format!("Card 1: {numbers} | {numbers}\n");
+
+        assert_eq!(Day04::solve_part1(&input).unwrap(), Answer::Unavailable);
+        assert_eq!(Day04::solve_part2(&input).unwrap(), 1);
+
+        let cards = Day04::parse(&input).unwrap();
+        assert_eq!(total_points(&cards, &Linear), Some(65));
+        assert_eq!(Doubling.points(64), Some(1 << 63));
+    }
+
+    #[test]
+    fn explains_the_cascade() {
+        let cards = Day04::parse(INPUT_TEST).unwrap();
+        let cascade = Cascade::new(&cards, &NextCards).unwrap();
+
+        assert_eq!(cascade.copies, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(cascade.won_by[3], [0, 1, 2]);
+        assert!(cascade.report().contains(
+            "Card 4: 8 copies, each winning card 5
+  1 original
+  1 from card 1
+  2 from card 2
+  4 from card 3
+"
+        ));
+    }
+
+    #[test]
+    fn other_copy_rules() {
+        let cards = Day04::parse(INPUT_TEST).unwrap();
+
+        // Card 2 wins card 1, card 3 wins cards 2 and 1, card 4 wins card 3
+        let previous = Cascade::new(&cards, &PreviousCards).unwrap();
+        assert_eq!(previous.copies, [6, 3, 2, 1, 1, 1]);
+
+        let capped = Capped {
+            rule: NextCards,
+            most: 1,
+        };
+        let capped = Cascade::new(&cards, &capped).unwrap();
+        assert_eq!(capped.copies, [1, 2, 3, 4, 5, 1]);
+        assert_eq!(capped.total(), 16);
+    }
+
+    #[test]
+    fn reports_endless_cascades() {
+        struct Both;
+
+        impl CopyRule for Both {
+            fn wins(&self, card: usize, matches: usize, cards: usize) -> Vec<usize> {
+                let mut won = NextCards.wins(card, matches, cards);
+                won.extend(PreviousCards.wins(card, matches, cards));
+                won
+            }
+        }
+
+        let cards = Day04::parse(INPUT_TEST).unwrap();
+
+        assert_eq!(
+            Cascade::new(&cards, &Both).unwrap_err(),
+            EndlessCascade { card: 0 }
+        );
+    }
+
+    #[test]
+    fn reports_numbers_out_of_range() {
+        let error = Day04::parse("Card 1: 1 2 | 300 4\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 15));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day04>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day04>(50);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,46 @@
+use std::{env, process};
+
+use advent_of_code_2023_day04::{Cascade, Day04, NextCards};
+use aoc_common::{input::Args, parse, solution::Solution};
+
+const CASCADE_USAGE: &str = "\
+Usage: advent-of-code-2023-day04 cascade [INPUT]
+
+Shows how many of each card part 2 ends up with, and which cards the copies were won from";
+
+fn main() {
+    if env::args().nth(1).as_deref() == Some("cascade") {
+        cascade_main();
+    } else {
+        aoc_common::solution::main::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+fn cascade_main() {
+    let args = match Args::parse_only(env!("CARGO_MANIFEST_DIR"), env::args().skip(2), &[]) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{CASCADE_USAGE}");
+            return;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{CASCADE_USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let input = args.input();
+    let input = parse::normalise(&input);
+    let cards = Day04::parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&args.source().name(), &input));
+        process::exit(1);
+    });
+
+    match Cascade::new(&cards, &NextCards) {
+        Ok(cascade) => print!("{}", cascade.report()),
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// A set of numbers from 0 to 255, one bit each, so that the numbers two sets share are
+// found with a few ANDs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberSet([u128; 2]);
+
+impl NumberSet {
+    pub fn insert(&mut self, number: u8) {
+        self.0[number as usize / 128] |= 1 << (number % 128);
+    }
+
+    pub fn contains(&self, number: u8) -> bool {
+        self.0[number as usize / 128] & (1 << (number % 128)) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|bits| bits.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == [0; 2]
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self([self.0[0] & other.0[0], self.0[1] & other.0[1]])
+    }
+
+    // In ascending order
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        (0..=u8::MAX).filter(move |&number| self.contains(number))
+    }
+}
+
+impl FromIterator<u8> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(numbers: I) -> Self {
+        let mut set = Self::default();
+
+        for number in numbers {
+            set.insert(number);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_numbers() {
+        let a = NumberSet::from_iter([0, 5, 127, 128, 255, 5]);
+        let b = NumberSet::from_iter([5, 128, 200]);
+
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [5, 128]);
+        assert!(a.intersection(&NumberSet::default()).is_empty());
+    }
+}
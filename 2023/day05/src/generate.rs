@@ -0,0 +1,61 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, seq::index, Rng};
+
+use crate::Day05;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+const SEED_PAIRS: usize = 10;
+
+// Every value, before and after each map, is below this
+const VALUES: u64 = 1 << 32;
+
+// `size` is the number of ranges in each map. A map's source ranges never overlap
+impl Generate for Day05 {
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let seeds = (0..SEED_PAIRS)
+            .map(|_| {
+                let start = rng.gen_range(0..VALUES / 2);
+                format!("{start} {}", rng.gen_range(1..VALUES / 16))
+            })
+            .collect::<Vec<_>>();
+
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for pair in CATEGORIES.windows(2) {
+            input.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+
+            // Consecutive cuts bound each source range; some are left out to leave gaps
+            let mut cuts = index::sample(rng, VALUES as usize, size + 1)
+                .into_iter()
+                .map(|cut| cut as u64)
+                .collect::<Vec<_>>();
+            cuts.sort_unstable();
+
+            for range in cuts.windows(2) {
+                let length = range[1] - range[0];
+                let length = if rng.gen_ratio(1, 4) {
+                    rng.gen_range(1..=length)
+                } else {
+                    length
+                };
+
+                let destination = rng.gen_range(0..=VALUES - length);
+                input.push_str(&format!("{destination} {} {length}\n", range[0]));
+            }
+        }
+
+        input
+    }
+}
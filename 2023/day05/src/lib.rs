@@ -0,0 +1,271 @@
+mod generate;
+
+use std::ops::Range;
+
+use aoc_common::{
+    interval::IntervalSet,
+    parallel,
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'input> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut blocks = parse::blocks(input);
+
+        let seeds_block = blocks
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "expected a list of seeds"))??;
+
+        let [line] = seeds_block[..] else {
+            let extra = seeds_block[1];
+            return Err(extra.error(extra.text, "expected a blank line after the seeds"));
+        };
+
+        let words = line
+            .labelled("seeds")?
+            .split_ascii_whitespace()
+            .collect::<Vec<_>>();
+
+        if words.is_empty() {
+            return Err(line.error_at_end("expected at least one seed"));
+        }
+
+        let seeds = words
+            .iter()
+            .map(|word| {
+                let seed = line.parse(word, "a seed number")?;
+                span(seed, 1).ok_or_else(|| line.error(word, "expected a smaller seed number"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Part 2 reads the seeds as (start, length) pairs, so has no answer if they don't pair up
+        let seed_ranges = if words.len().is_multiple_of(2) {
+            let ranges = words
+                .chunks(2)
+                .map(|pair| {
+                    let start = line.parse(pair[0], "a seed number")?;
+                    let num = line.parse(pair[1], "a number of seeds")?;
+                    span(start, num).ok_or_else(|| {
+                        line.error(
+                            pair[0],
+                            format!("expected the {num} seeds from here to fit in an i64"),
+                        )
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            Some(ranges)
+        } else {
+            None
+        };
+
+        let remappers = blocks
+            .map(|block| build_almanac_remapper(&block?))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac {
+            seeds,
+            seed_ranges,
+            remappers,
+        })
+    }
+
+    fn part1(almanac: &Self::Parsed<'_>) -> Answer {
+        find_optimal_location(&almanac.seeds, &almanac.remappers)
+            .map_or(Answer::Unavailable, Answer::from)
+    }
+
+    fn part2(almanac: &Self::Parsed<'_>) -> Answer {
+        almanac
+            .seed_ranges
+            .as_ref()
+            .and_then(|seeds| find_optimal_location(seeds, &almanac.remappers))
+            .map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<Range<i64>>,
+    seed_ranges: Option<Vec<Range<i64>>>,
+    remappers: Vec<AlmanacRemapper>,
+}
+
+pub struct Remap {
+    source: Range<i64>,
+    offset: i64,
+}
+
+pub struct AlmanacRemapper {
+    remaps: Vec<Remap>,
+}
+
+impl AlmanacRemapper {
+    fn new() -> Self {
+        Self { remaps: vec![] }
+    }
+
+    fn add(&mut self, remap: Remap) {
+        self.remaps.push(remap);
+    }
+
+    // Whole ranges of values are remapped at once, rather than one value at a time
+    fn remap(&self, values: &IntervalSet) -> IntervalSet {
+        let mut unmapped = values.clone();
+        let mut remapped = IntervalSet::new();
+
+        for remap in &self.remaps {
+            let source = IntervalSet::from(remap.source.clone());
+
+            remapped = remapped.union(&unmapped.intersection(&source).translate(remap.offset));
+            unmapped = unmapped.difference(&source);
+        }
+
+        // Anything not covered by a remap keeps its value
+        remapped.union(&unmapped)
+    }
+}
+
+// Each range of seeds is followed through the maps on its own, so that they can be
+// shared out between threads. `None` if every range is empty
+fn find_optimal_location(seeds: &[Range<i64>], remappers: &[AlmanacRemapper]) -> Option<i64> {
+    parallel::map(seeds, |seeds| {
+        let locations = remappers.iter().enumerate().fold(
+            IntervalSet::from(seeds.clone()),
+            |values, (i, remapper)| {
+                let values = remapper.remap(&values);
+                trace!(
+                    "map",
+                    seeds = seeds,
+                    number = i + 1,
+                    ranges = values.ranges().len()
+                );
+                values
+            },
+        );
+
+        let nearest = locations.min();
+        trace!("seeds", seeds = seeds, nearest = nearest);
+        nearest
+    })
+    .into_iter()
+    .flatten()
+    .min()
+}
+
+// The `num` values from `start`, if they fit in the `i64`s that intervals are made of
+fn span(start: u64, num: u64) -> Option<Range<i64>> {
+    let end = i64::try_from(start.checked_add(num)?).ok()?;
+
+    Some(start as i64..end)
+}
+
+fn build_almanac_remapper(lines: &[Line<'_>]) -> Result<AlmanacRemapper, ParseError> {
+    // Blocks are never empty
+    let (header, maps) = lines.split_first().unwrap();
+
+    if !header.text.ends_with(" map:") {
+        return Err(header.error(header.text, "expected a `<from>-to-<to> map:` header"));
+    }
+
+    let mut remapper = AlmanacRemapper::new();
+
+    for line in maps {
+        let [to_start, from_start, num] = line
+            .parse_all::<u64>(line.text, "a number")?
+            .try_into()
+            .map_err(|_| line.error(line.text, "expected three numbers"))?;
+
+        let (Some(source), Some(target)) = (span(from_start, num), span(to_start, num)) else {
+            return Err(line.error(line.text, "expected the remapped ranges to fit in an i64"));
+        };
+
+        remapper.add(Remap {
+            offset: target.start - source.start,
+            source,
+        });
+    }
+
+    Ok(remapper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day05::solve_part1(INPUT_TEST).unwrap(), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day05::solve_part2(INPUT_TEST).unwrap(), 46);
+    }
+
+    #[test]
+    fn reports_extra_blank_lines_between_sections() {
+        let input = INPUT_TEST.replacen("\n\n", "\n\n\n", 1);
+        let error = Day05::solve_part1(&input).unwrap_err();
+
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn reports_no_seeds() {
+        let error = Day05::solve_part1("seeds:\n\nseed-to-soil map:\n1 2 3\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+
+    #[test]
+    fn empty_seed_ranges_have_no_answer() {
+        let input = "seeds: 5 0\n\nseed-to-soil map:\n1 2 3\n";
+
+        assert_eq!(Day05::solve_part1(input).unwrap(), 0);
+        assert_eq!(Day05::solve_part2(input).unwrap(), Answer::Unavailable);
+    }
+
+    #[test]
+    fn unpaired_seeds_have_no_part2() {
+        let input = "seeds: 5 1 7\n\nseed-to-soil map:\n1 2 3\n";
+
+        assert_eq!(Day05::solve_part1(input).unwrap(), 1);
+        assert_eq!(Day05::solve_part2(input).unwrap(), Answer::Unavailable);
+    }
+
+    #[test]
+    fn reports_seed_ranges_that_overflow() {
+        let input = format!(
+            "seeds: 1 2 {} 10\n\nseed-to-soil map:\n1 2 3\n",
+            i64::MAX - 5
+        );
+        let error = Day05::solve_part1(&input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 12));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day05>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day05>(10);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day05::Day05;
+
+fn main() {
+    aoc_common::solution::main::<Day05>(env!("CARGO_MANIFEST_DIR"));
+}
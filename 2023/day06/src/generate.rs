@@ -0,0 +1,47 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day06;
+
+// `size` is the number of races. Every record can be beaten, both by the races on their
+// own and by the one long race they make with the spaces taken out, which only fits
+// in a u64 for up to 4 races or so. Beyond 10 it hardly ever does, and the search for
+// races that fit could go on indefinitely
+impl Generate for Day06 {
+    const DEFAULT_SIZE: usize = 4;
+    const MAX_SIZE: usize = 10;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        loop {
+            let races = (0..size)
+                .map(|_| {
+                    let time = rng.gen_range(7..100u64);
+                    (time, rng.gen_range(0..time * time / 4))
+                })
+                .collect::<Vec<_>>();
+
+            let kerned = |value: fn(&(u64, u64)) -> u64| {
+                races
+                    .iter()
+                    .map(|race| value(race).to_string())
+                    .collect::<String>()
+                    .parse::<u64>()
+                    .ok()
+            };
+
+            match (kerned(|race| race.0), kerned(|race| race.1)) {
+                (Some(time), Some(record)) if u128::from(record) < u128::from(time).pow(2) / 4 => {}
+                _ => continue,
+            }
+
+            let times = races.iter().map(|(time, _)| format!("{time:>5}"));
+            let records = races.iter().map(|(_, record)| format!("{record:>5}"));
+
+            return format!(
+                "Time:    {}\nDistance:{}\n",
+                times.collect::<String>(),
+                records.collect::<String>()
+            );
+        }
+    }
+}
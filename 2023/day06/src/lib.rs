@@ -0,0 +1,191 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+// The rules are as follows:
+// - Initial velocity is equal to the time we accelerate
+//     for since we accelerate at a rate of one unit per second.
+//     - There is no mention of slowing or accelerating from zero,
+//         so we can assume instantaneous acceleration and a constant
+//         velocity once we have started moving.
+// - Duration spent traveling is equal to the total time minus the
+//     time we spent accelerating.
+//
+// Or as formulas:
+//   v = acc_time
+//   d = v * t
+//   d = v * (time - acc_time)
+//   d = acc_time * (time - acc_time)
+//
+// We rearrange to form a quadratic equation:
+//   d = acc_time * (time - acc_time)
+//   d = acc_time * time - acc_time^2
+//   acc_time^2 - time * acc_time + d = 0
+//
+// Our inputs are time and target distance, so we can solve for acc_time
+//   using the quadratic formula:
+//
+//   x = (-b +- sqrt(b^2 - 4ac)) / 2a
+//
+// Where:
+//
+//   a = 1
+//   b = -time
+//   c = (target) distance
+//   x = time spent accelerating
+//
+// If we find the two solutions, we will have found the two times where
+//   the boat perfectly matches the target distance.
+// We can then base our answer on the rounded values of those, where we round the lower
+//   value up and the higher value down.
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'input> = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let (times_line, times) = parse_sheet_line(lines.next(), "Time", input)?;
+        let (distances_line, distances) = parse_sheet_line(lines.next(), "Distance", input)?;
+
+        if times.len() != distances.len() {
+            return Err(distances_line.error(
+                distances_line.text,
+                format!("expected {} distances, one per time", times.len()),
+            ));
+        }
+
+        Ok(Races {
+            time_distance_pairs: times.into_iter().zip(distances).collect(),
+            kerned_time_distance_pair: (kerned(times_line)?, kerned(distances_line)?),
+        })
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> Answer {
+        races
+            .time_distance_pairs
+            .iter()
+            .copied()
+            .map(find_range)
+            .product::<u64>()
+            .into()
+    }
+
+    fn part2(races: &Self::Parsed<'_>) -> Answer {
+        find_range(races.kerned_time_distance_pair).into()
+    }
+}
+
+// The sheet can be read either as several races or, ignoring the spaces, as one long race
+pub struct Races {
+    time_distance_pairs: Vec<(u64, u64)>,
+    kerned_time_distance_pair: (u64, u64),
+}
+
+// Reads a `<label>: <numbers>` line of the sheet
+fn parse_sheet_line<'a>(
+    line: Option<Line<'a>>,
+    label: &str,
+    input: &str,
+) -> Result<(Line<'a>, Vec<u64>), ParseError> {
+    let line =
+        line.ok_or_else(|| ParseError::end_of_input(input, format!("expected a `{label}:` line")))?;
+    let numbers = line.labelled(label)?;
+
+    Ok((line, line.parse_all(numbers, "a number")?))
+}
+
+// The numbers on a sheet line with the spaces between them ignored
+fn kerned(line: Line<'_>) -> Result<u64, ParseError> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+
+    numbers
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<String>()
+        .parse()
+        .map_err(|_| line.error(numbers.trim(), "expected a number that fits in 64 bits"))
+}
+
+fn find_range((time, record_distance): (u64, u64)) -> u64 {
+    // x = (-b +- sqrt(b^2 - 4ac)) / 2a
+    //
+    // a = 1
+    // b = -time
+    // c = (target) distance
+    // x = (-time +- sqrt((-time)^2 - 4*distance)) / 2
+    // x = (-time +- sqrt(time^2 - 4*distance)) / 2
+
+    let time = time as f64;
+    let distance = (record_distance + 1) as f64;
+
+    let acc1 = (time + ((time * time) - 4.0 * distance).sqrt()) / 2.0;
+    let acc2 = (time - ((time * time) - 4.0 * distance).sqrt()) / 2.0;
+
+    let (acc1, acc2) = if acc1 < acc2 {
+        (acc1, acc2)
+    } else {
+        (acc2, acc1)
+    };
+
+    let acc1 = acc1.ceil() as u64;
+    let acc2 = acc2.floor() as u64;
+
+    trace!(
+        "race",
+        time = time as u64,
+        record = record_distance,
+        shortest_hold = acc1,
+        longest_hold = acc2
+    );
+
+    1 + acc2 - acc1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day06::solve_part1(INPUT_TEST).unwrap(), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day06::solve_part2(INPUT_TEST).unwrap(), 71503);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day06>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day06>(4);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day06>(0..20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day06::Day06;
+
+fn main() {
+    aoc_common::solution::main::<Day06>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,38 @@
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day06;
+
+// Tries every length of time to hold the button for, rather than solving for where the
+// distance crosses the record
+impl Reference for Day06 {
+    // Three races kern into a race of up to a million milliseconds
+    const REFERENCE_SIZE: usize = 3;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        let mut lines = input.lines().map(|line| {
+            let numbers = line.split_whitespace().skip(1);
+
+            match part {
+                Part::One => numbers.map(|number| number.parse().unwrap()).collect(),
+                Part::Two => vec![numbers.collect::<String>().parse().unwrap()],
+            }
+        });
+
+        let times: Vec<u64> = lines.next().unwrap();
+        let records: Vec<u64> = lines.next().unwrap();
+
+        times
+            .iter()
+            .zip(&records)
+            .map(|(&time, &record)| {
+                (0..=time)
+                    .filter(|held| held * (time - held) > record)
+                    .count()
+            })
+            .product::<usize>()
+            .into()
+    }
+}
@@ -0,0 +1,29 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day07;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+// `size` is the number of hands
+impl Generate for Day07 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            // Drawing from fewer kinds of card makes the better hands more likely
+            let kinds = rng.gen_range(1..=CARDS.len());
+            let first = rng.gen_range(0..=CARDS.len() - kinds);
+
+            let hand = (0..5)
+                .map(|_| CARDS[first + rng.gen_range(0..kinds)] as char)
+                .collect::<String>();
+
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..1000)));
+        }
+
+        input
+    }
+}
@@ -1,32 +1,51 @@
-const INPUT_TEST: &str = include_str!("../input_test.txt");
-const INPUT: &str = include_str!("../input.txt");
+mod generate;
 
-fn main() {
-    let test_result = solve::<Part1Strategy>(INPUT_TEST);
-    println!("Test Part 1: {}", test_result);
-    assert!(test_result == 6440);
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
 
-    let part_1_result = solve::<Part1Strategy>(INPUT);
-    println!("Part 1: {}", part_1_result);
+pub struct Day07;
 
-    let test_result = solve::<Part2Strategy>(INPUT_TEST);
-    println!("Test Part 2: {}", test_result);
-    assert!(test_result == 5905);
+impl Solution for Day07 {
+    const DAY: u8 = 7;
 
-    let part_2_result = solve::<Part2Strategy>(INPUT);
-    println!("Part 2: {}", part_2_result);
+    type Parsed<'input> = Vec<Deal>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input).map(Deal::try_from).collect()
+    }
+
+    fn part1(deals: &Self::Parsed<'_>) -> Answer {
+        solve::<Part1Strategy>(deals).into()
+    }
+
+    fn part2(deals: &Self::Parsed<'_>) -> Answer {
+        solve::<Part2Strategy>(deals).into()
+    }
 }
 
 const MAX_CARDS: usize = 13;
+const HAND_SIZE: usize = 5;
 
-fn solve<S: Strategy>(input: &str) -> u32 {
-    let mut hands: Vec<Hand<S>> = input.lines().map(Hand::new).collect();
+fn solve<S: Strategy>(deals: &[Deal]) -> u32 {
+    let mut hands: Vec<Hand<S>> = deals.iter().map(Hand::new).collect();
     hands.sort();
 
     hands
         .iter()
         .enumerate()
-        .map(|(i, hand)| (i + 1) as u32 * hand.bid)
+        .map(|(i, hand)| {
+            trace!(
+                "hand",
+                rank = i + 1,
+                cards = hand.cards,
+                category = hand.category,
+                bid = hand.bid
+            );
+            (i + 1) as u32 * hand.bid
+        })
         .sum()
 }
 
@@ -69,7 +88,7 @@ struct Hand<S: Strategy> {
 }
 
 impl<S: Strategy> Hand<S> {
-    fn category(cards: &Vec<Card>) -> Category {
+    fn category(cards: &[Card]) -> Category {
         let counts =
             cards
                 .iter()
@@ -85,7 +104,7 @@ impl<S: Strategy> Hand<S> {
             5 => Category::FiveOfAKind,
             4 => Category::FourOfAKind,
             3 => {
-                if counts.iter().any(|c| *c == 2) {
+                if counts.contains(&2) {
                     Category::FullHouse
                 } else {
                     Category::ThreeOfAKind
@@ -103,22 +122,43 @@ impl<S: Strategy> Hand<S> {
         }
     }
 
-    fn new(input: &str) -> Self {
-        let (hand, bid) = input.split_once(" ").unwrap();
-
-        let cards: Vec<Card> = hand.chars().map(|c: char| c.into()).collect();
-        let bid = bid.trim().parse().unwrap();
+    fn new(deal: &Deal) -> Self {
+        let cards = deal.cards.clone();
         let category = Self::category(&cards);
 
         Self {
             cards,
-            bid,
+            bid: deal.bid,
             category,
             _phantom: std::marker::PhantomData,
         }
     }
 }
 
+pub struct Deal {
+    cards: Vec<Card>,
+    bid: u32,
+}
+
+impl TryFrom<Line<'_>> for Deal {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let (hand, bid) = line.split_once(line.text, " ")?;
+
+        let cards = line.parse_chars(hand, "a card, one of 23456789TJQKA", Card::from_char)?;
+
+        if cards.len() != HAND_SIZE {
+            return Err(line.error(hand, format!("expected a hand of {HAND_SIZE} cards")));
+        }
+
+        Ok(Self {
+            cards,
+            bid: line.parse(bid, "a bid")?,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 enum Card {
     Two,
@@ -136,9 +176,9 @@ enum Card {
     Ace,
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl Card {
+    fn from_char(value: char) -> Option<Self> {
+        Some(match value {
             '2' => Card::Two,
             '3' => Card::Three,
             '4' => Card::Four,
@@ -152,8 +192,8 @@ impl From<char> for Card {
             'Q' => Card::Queen,
             'K' => Card::King,
             'A' => Card::Ace,
-            _ => unreachable!(), // Assuming valid input
-        }
+            _ => return None,
+        })
     }
 }
 
@@ -185,7 +225,7 @@ impl<S: Strategy> PartialOrd for Hand<S> {
 impl<S: Strategy> Ord for Hand<S> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.category.cmp(&other.category) {
-            std::cmp::Ordering::Equal => self.cards_ord(&other),
+            std::cmp::Ordering::Equal => self.cards_ord(other),
             other => other,
         }
     }
@@ -243,3 +283,51 @@ impl Strategy for Part2Strategy {
         counts
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day07::solve_part1(INPUT_TEST).unwrap(), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day07::solve_part2(INPUT_TEST).unwrap(), 5905);
+    }
+
+    #[test]
+    fn crlf_and_trailing_blank_lines_are_accepted() {
+        let input = INPUT_TEST.replace('\n', "\r\n") + "\r\n\r\n";
+
+        assert_eq!(Day07::solve_part1(&input).unwrap(), 6440);
+    }
+
+    #[test]
+    fn reports_an_unknown_card() {
+        let error = Day07::solve_part1("32T3K 765\nT55X5 684\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "X");
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day07>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day07>(200);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day07::Day07;
+
+fn main() {
+    aoc_common::solution::main::<Day07>(env!("CARGO_MANIFEST_DIR"));
+}
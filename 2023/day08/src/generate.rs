@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day08;
+
+const GHOSTS: usize = 6;
+
+// How many times round the directions each ghost's loop takes; different primes, so
+// that the ghosts only line up after a good while
+const LAPS: [usize; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+
+// `size` is the number of directions. As in the real input, each ghost goes round its
+// own loop, which takes a whole number of times round the directions and ends on its
+// only `Z` node, so that every ghost arrives at a `Z` node just once per loop. The
+// directions still matter, as some steps can land on either of two nodes
+impl Generate for Day08 {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let directions = (0..size)
+            .map(|_| if rng.gen() { 'L' } else { 'R' })
+            .collect::<String>();
+
+        let mut names = Names::new();
+        let mut laps = LAPS;
+        laps.shuffle(rng);
+
+        let mut maps = Vec::new();
+
+        for (ghost, laps) in laps.iter().take(GHOSTS).enumerate() {
+            let (start, end) = if ghost == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (names.next(rng, 'A'), names.next(rng, 'Z'))
+            };
+
+            // The nodes at each step round the loop: one or two, except at the end
+            let length = laps * size;
+            let mut steps = (0..length - 1)
+                .map(|_| {
+                    (0..rng.gen_range(1..=2))
+                        .map(|_| names.next(rng, 'B'))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            steps.push(vec![end]);
+
+            let targets = |step: &Vec<String>| [step[0].clone(), step[step.len() - 1].clone()];
+
+            maps.push((start, targets(&steps[0])));
+
+            for (i, step) in steps.iter().enumerate() {
+                let next = targets(&steps[(i + 1) % length]);
+
+                for node in step {
+                    maps.push((node.clone(), next.clone()));
+                }
+            }
+        }
+
+        maps.shuffle(rng);
+
+        let mut input = format!("{directions}\n\n");
+
+        for (node, [left, right]) in maps {
+            input.push_str(&format!("{node} = ({left}, {right})\n"));
+        }
+
+        input
+    }
+}
+
+// Three character names, of which only the starts end in `A` and only the ends in `Z`
+struct Names {
+    used: HashSet<String>,
+}
+
+impl Names {
+    fn new() -> Self {
+        Self {
+            used: HashSet::from(["AAA".to_string(), "ZZZ".to_string()]),
+        }
+    }
+
+    // `last` is `A` or `Z` for a start or an end, or anything else for neither
+    fn next(&mut self, rng: &mut StdRng, last: char) -> String {
+        const CHARS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+
+        loop {
+            let mut name = (0..2)
+                .map(|_| *CHARS.choose(rng).unwrap() as char)
+                .collect::<String>();
+
+            name.push(match last {
+                'A' | 'Z' => last,
+                _ => *CHARS.choose(rng).unwrap() as char,
+            });
+
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_common::{
+    cycle::{self, Schedule},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed<'input> = Network<'input>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut blocks = parse::blocks(input);
+
+        let directions_block = blocks
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "expected a list of directions"))??;
+
+        let [line] = directions_block[..] else {
+            let extra = directions_block[1];
+            return Err(extra.error(extra.text, "expected a blank line after the directions"));
+        };
+        let directions = line.text;
+
+        line.parse_chars(directions, "`L` or `R`", |c| {
+            matches!(c, 'L' | 'R').then_some(())
+        })?;
+
+        let lines = match blocks.next() {
+            Some(block) => block?,
+            None => Vec::new(),
+        };
+        let entries = lines
+            .iter()
+            .map(|&line| parse_map(line))
+            .collect::<Result<Vec<_>, _>>()?;
+        let maps = entries.iter().copied().collect::<HashMap<_, _>>();
+
+        for (line, (_, targets)) in lines.iter().zip(&entries) {
+            if let Some(target) = targets.iter().find(|target| !maps.contains_key(*target)) {
+                return Err(line.error(target, "expected a node defined in the network"));
+            }
+        }
+
+        if let Some(block) = blocks.next() {
+            let line = block?[0];
+            return Err(line.error(line.text, "expected only the directions and the network"));
+        }
+
+        Ok(Network { directions, maps })
+    }
+
+    fn part1(network: &Self::Parsed<'_>) -> Answer {
+        let mut directions = network.directions.chars().map(as_direction_index).cycle();
+
+        // The part 2 examples have no `AAA`
+        if !network.maps.contains_key("AAA") {
+            return Answer::Unavailable;
+        }
+
+        // Past this many steps, we're going round a loop that doesn't reach `ZZZ`
+        let most_steps = network.maps.len() * network.directions.len();
+
+        let mut steps = 0;
+        let mut current_key = "AAA";
+
+        while current_key != "ZZZ" {
+            if steps > most_steps {
+                return Answer::Unavailable;
+            }
+
+            current_key = network.maps[current_key][directions.next().unwrap()];
+            steps += 1;
+
+            trace!("step", number = steps, node = current_key);
+        }
+
+        steps.into()
+    }
+
+    fn part2(network: &Self::Parsed<'_>) -> Answer {
+        let schedules = network
+            .maps
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|start| network.arrivals_from(start))
+            .collect::<Vec<_>>();
+
+        cycle::first_common_step(&schedules).map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+#[derive(Debug)]
+pub struct Network<'a> {
+    directions: &'a str,
+    maps: HashMap<&'a str, [&'a str; 2]>,
+}
+
+fn as_direction_index(c: char) -> usize {
+    match c {
+        'L' => 0,
+        'R' => 1,
+        _ => unreachable!(), // Checked when parsing
+    }
+}
+
+fn parse_map(line: Line<'_>) -> Result<(&str, [&str; 2]), ParseError> {
+    let (key, rest) = line.split_once(line.text, " = (")?;
+    let (left, right) = line.split_once(rest, ", ")?;
+    let right = right
+        .strip_suffix(')')
+        .ok_or_else(|| line.error_at_end("expected `)`"))?;
+
+    Ok((key, [left, right]))
+}
+
+impl Network<'_> {
+    // The steps after which a ghost starting from `start` is on a `Z` node. It goes
+    // round in a loop once it's somewhere it has been before, at the same point in the
+    // directions
+    fn arrivals_from(&self, start: &str) -> Schedule {
+        let directions = self.directions.as_bytes();
+
+        let mut arrivals = Vec::new();
+
+        let looping = cycle::find_loop_by_key(
+            &mut (start, 0),
+            |(key, steps)| {
+                if key.ends_with('Z') {
+                    arrivals.push(*steps);
+                }
+
+                let direction = directions[*steps % directions.len()] as char;
+                *key = self.maps[key][as_direction_index(direction)];
+                *steps += 1;
+            },
+            |(key, steps)| (*key, steps % directions.len()),
+        );
+
+        trace!("ghost", start = start, arrivals = arrivals);
+
+        Schedule {
+            looping,
+            steps: arrivals,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+    const INPUT_TEST_PART2: &str = include_str!("../input_test_part2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day08::solve_part1(INPUT_TEST).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day08::solve_part2(INPUT_TEST_PART2).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_with_loops_that_dont_start_at_zero() {
+        // The first ghost is on a Z after 2, 4, 6, ... steps, and the second after 1, 4, 7, ...
+        let input = "\
+L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22Z, XXX)
+XXX = (XXX, XXX)
+";
+
+        assert_eq!(Day08::solve_part2(input).unwrap(), 4);
+    }
+
+    #[test]
+    fn reports_undefined_nodes() {
+        let error = Day08::parse("LR\n\nAAA = (BBB, CCC)\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 8));
+    }
+
+    #[test]
+    fn no_way_to_zzz_has_no_answer() {
+        let input = "LR\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+
+        assert_eq!(Day08::solve_part1(input).unwrap(), Answer::Unavailable);
+        assert_eq!(
+            Day08::solve_part1(INPUT_TEST_PART2).unwrap(),
+            Answer::Unavailable
+        );
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day08>(INPUT_TEST);
+        aoc_common::generate::assert_truncated_inputs_solve::<Day08>(INPUT_TEST_PART2);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day08>(10);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
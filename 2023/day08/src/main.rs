@@ -0,0 +1,5 @@
+use advent_of_code_2023_day08::Day08;
+
+fn main() {
+    aoc_common::solution::main::<Day08>(env!("CARGO_MANIFEST_DIR"));
+}
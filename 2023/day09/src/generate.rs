@@ -0,0 +1,37 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day09;
+
+const VALUES: i64 = 21;
+
+// `size` is the number of histories. Each follows a polynomial of degree at most 6 with
+// small coefficients, so that there are always enough values to extrapolate it
+impl Generate for Day09 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let coefficients = (0..=rng.gen_range(0..=6))
+                .map(|_| rng.gen_range(-10..=10))
+                .collect::<Vec<i64>>();
+
+            let history = (0..VALUES)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+
+            input.push_str(&history.join(" "));
+            input.push('\n');
+        }
+
+        input
+    }
+}
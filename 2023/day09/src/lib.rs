@@ -0,0 +1,123 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use aoc_common::{
+    parse::{self, ParseError},
+    polynomial::Polynomial,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'input> = Vec<History>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let values: Vec<i64> = line.parse_all(line.text, "a number")?;
+
+                let polynomial = Polynomial::fit(&values).map_err(|error| {
+                    line.error(line.text, format!("could not extrapolate history: {error}"))
+                })?;
+
+                Ok(History {
+                    len: values.len(),
+                    polynomial,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Parsed<'_>) -> Answer {
+        histories
+            .iter()
+            .enumerate()
+            .map(|(i, history)| {
+                let next = history.polynomial.value_at(history.len as i128).unwrap();
+
+                trace!(
+                    "history",
+                    number = i + 1,
+                    degree = history.polynomial.degree(),
+                    next = next
+                );
+                next
+            })
+            .sum::<i128>()
+            .into()
+    }
+
+    fn part2(histories: &Self::Parsed<'_>) -> Answer {
+        histories
+            .iter()
+            .enumerate()
+            .map(|(i, history)| {
+                let previous = history.polynomial.value_at(-1).unwrap();
+
+                trace!(
+                    "history",
+                    number = i + 1,
+                    degree = history.polynomial.degree(),
+                    previous = previous
+                );
+                previous
+            })
+            .sum::<i128>()
+            .into()
+    }
+}
+
+pub struct History {
+    len: usize,
+    polynomial: Polynomial,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day09::solve_part1(INPUT_TEST).unwrap(), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day09::solve_part2(INPUT_TEST).unwrap(), 2);
+    }
+
+    #[test]
+    fn reports_a_history_that_cant_be_extrapolated() {
+        let error = Day09::parse("0 3 6 9\n1 2 4 8 16\n").err().unwrap();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day09>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day09>(50);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day09>(0..20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day09::Day09;
+
+fn main() {
+    aoc_common::solution::main::<Day09>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,38 @@
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day09;
+
+// Works down rows of differences until they're all zero and back up again, as the
+// puzzle describes, rather than fitting a polynomial
+impl Reference for Day09 {
+    const REFERENCE_SIZE: usize = 20;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        input
+            .lines()
+            .map(|line| {
+                let mut row = line
+                    .split_whitespace()
+                    .map(|value| value.parse::<i128>().unwrap())
+                    .collect::<Vec<_>>();
+
+                if part == Part::Two {
+                    row.reverse();
+                }
+
+                let mut next = 0;
+
+                while row.iter().any(|value| *value != 0) {
+                    next += row[row.len() - 1];
+                    row = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+                }
+
+                next
+            })
+            .sum::<i128>()
+            .into()
+    }
+}
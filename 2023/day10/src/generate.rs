@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    generate::{self, Generate},
+    geometry::{Direction, Position},
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day10;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+// `size` is the side of the grid, at least 2. The tiles that the loop doesn't use are
+// random pipes and ground, but for those next to the start, which would make it
+// ambiguous
+impl Generate for Day10 {
+    const DEFAULT_SIZE: usize = 140;
+    const MIN_SIZE: usize = 2;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let (start, steps) = generate::outline(rng, size);
+
+        // Each tile round the loop joins the way it was entered from to the way it leaves
+        let mut pipes = HashMap::new();
+        let mut position = start;
+
+        for (i, leaving) in steps.iter().enumerate() {
+            let entered = steps[(i + steps.len() - 1) % steps.len()].opposite();
+            pipes.insert(position, pipe(entered, *leaving));
+            position += leaving.as_offset();
+        }
+
+        pipes.insert(start, 'S');
+
+        generate::grid(rng, size, size, |rng, x, y| {
+            let position = Position::from((x, y));
+
+            match pipes.get(&position) {
+                Some(pipe) => *pipe,
+                None if position.manhattan_distance(&start) == 1 => '.',
+                None if rng.gen_ratio(1, 3) => '.',
+                None => *PIPES.choose(rng).unwrap(),
+            }
+        })
+    }
+}
+
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+
+    match (a, b) {
+        (Up, Down) | (Down, Up) => '|',
+        (Left, Right) | (Right, Left) => '-',
+        (Up, Right) | (Right, Up) => 'L',
+        (Up, Left) | (Left, Up) => 'J',
+        (Down, Left) | (Left, Down) => '7',
+        (Down, Right) | (Right, Down) => 'F',
+        _ => unreachable!("a loop never turns back on itself"),
+    }
+}
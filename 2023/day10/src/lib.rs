@@ -0,0 +1,335 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    geometry::{Direction, Position},
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'input> = (Position, Map);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1((start, map): &Self::Parsed<'_>) -> Answer {
+        (map.extract_loop(start).len() / 2).into()
+    }
+
+    fn part2((start, map): &Self::Parsed<'_>) -> Answer {
+        let mut map = map.clone();
+        let main_loop = map
+            .extract_loop(start)
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>();
+
+        // Mark all but the main loop as Empty
+        for (position, cell) in map.layout.iter_mut() {
+            if main_loop.contains(&position) {
+                continue;
+            }
+
+            *cell = MapCell::Empty;
+        }
+
+        let mut internal_cells = 0u32;
+
+        for (y, row) in map.layout.rows().enumerate() {
+            let inside_before = internal_cells;
+            let mut counting = false;
+            let mut hoping_for = None;
+
+            for cell in row {
+                match cell {
+                    MapCell::Pipe(Pipe(Direction::Up, Direction::Down)) => {
+                        counting = !counting;
+                    }
+                    MapCell::Pipe(Pipe(this_vertical, _)) if this_vertical.is_vertical() => {
+                        match hoping_for {
+                            None => {
+                                hoping_for = Some(this_vertical.opposite());
+                            }
+                            Some(hoping_for_vertical) => {
+                                if hoping_for_vertical == *this_vertical {
+                                    counting = !counting;
+                                }
+
+                                hoping_for = None;
+                            }
+                        }
+                    }
+                    MapCell::Empty => {
+                        if counting {
+                            internal_cells += 1;
+                        }
+                    }
+                    MapCell::Pipe(_) => {}
+                }
+            }
+
+            trace!(
+                "row",
+                number = y + 1,
+                inside = internal_cells - inside_before
+            );
+        }
+
+        internal_cells.into()
+    }
+}
+
+fn parse_map(input: &str) -> Result<(Position, Map), ParseError> {
+    let tiles = Grid::try_parse(input, "a pipe, one of `.-|LF7JS`", |c| {
+        matches!(c, '.' | '-' | '|' | 'L' | 'F' | '7' | 'J' | 'S').then_some(c)
+    })?;
+    let start = tiles
+        .find(|c| *c == 'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a start tile `S`"))?;
+
+    let mut layout = tiles.map(|c| match c {
+        '-' => MapCell::Pipe(Pipe(Direction::Left, Direction::Right)),
+        '|' => MapCell::Pipe(Pipe(Direction::Up, Direction::Down)),
+        'L' => MapCell::Pipe(Pipe(Direction::Up, Direction::Right)),
+        'F' => MapCell::Pipe(Pipe(Direction::Down, Direction::Right)),
+        '7' => MapCell::Pipe(Pipe(Direction::Down, Direction::Left)),
+        'J' => MapCell::Pipe(Pipe(Direction::Up, Direction::Left)),
+        // We don't know what the start pipe is; calculate it later
+        _ => MapCell::Empty,
+    });
+
+    let start_cell_pipe = detect_start_cell_pipe(&layout, &start).ok_or_else(|| {
+        ParseError::new(
+            start.y as usize + 1,
+            start.x as usize + 1,
+            "S",
+            "expected the start to connect to exactly two pipes",
+        )
+    })?;
+    layout[start] = MapCell::Pipe(start_cell_pipe);
+
+    let map = Map { layout };
+
+    // The pipe where the loop breaks off, either leading nowhere or into a pipe that
+    // doesn't lead back
+    if let Err(broken) = map.walk_loop(&start) {
+        return Err(ParseError::new(
+            broken.y as usize + 1,
+            broken.x as usize + 1,
+            &tiles[broken].to_string(),
+            "expected the pipe to lead on round the loop from `S`",
+        ));
+    }
+
+    Ok((start, map))
+}
+
+fn detect_start_cell_pipe(layout: &Grid<MapCell>, start: &Position) -> Option<Pipe> {
+    let mut connections = 0;
+
+    for direction in Direction::ALL {
+        // e.g. looking for a south connection on the pipe to the north of us
+        let wanted_connection = direction.opposite();
+
+        if let Some(MapCell::Pipe(pipe)) = layout.get(&(*start + direction.as_offset())) {
+            let other_pipe_connections = pipe.as_mask();
+            let connected_to_us = other_pipe_connections & as_mask(&wanted_connection) != 0;
+
+            if connected_to_us {
+                connections |= as_mask(&direction);
+            }
+        }
+    }
+
+    Pipe::from_mask(connections)
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    layout: Grid<MapCell>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MapCell {
+    Empty,
+    Pipe(Pipe),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Pipe(Direction, Direction);
+
+impl Pipe {
+    fn from_mask(mask: u8) -> Option<Self> {
+        match mask {
+            0b0101 => Some(Self(Direction::Left, Direction::Right)),
+            0b1010 => Some(Self(Direction::Up, Direction::Down)),
+            0b1100 => Some(Self(Direction::Up, Direction::Right)),
+            0b0110 => Some(Self(Direction::Down, Direction::Right)),
+            0b0011 => Some(Self(Direction::Down, Direction::Left)),
+            0b1001 => Some(Self(Direction::Up, Direction::Left)),
+            _ => None,
+        }
+    }
+
+    fn as_mask(&self) -> u8 {
+        as_mask(&self.0) | as_mask(&self.1)
+    }
+
+    fn get_next_direction(&self, entered_from: Option<Direction>) -> Direction {
+        match entered_from {
+            None => self.0,
+            Some(entered) => {
+                if self.0 == entered {
+                    self.1
+                } else {
+                    self.0
+                }
+            }
+        }
+    }
+}
+
+fn as_mask(direction: &Direction) -> u8 {
+    match direction {
+        Direction::Up => 0b1000,
+        Direction::Right => 0b0100,
+        Direction::Down => 0b0010,
+        Direction::Left => 0b0001,
+    }
+}
+
+impl Map {
+    fn extract_loop(&self, start: &Position) -> Vec<Position> {
+        let steps = self
+            .walk_loop(start)
+            .expect("the loop is checked when parsing");
+
+        for (i, (from, direction)) in steps.iter().enumerate() {
+            let to = *from + direction.as_offset();
+
+            trace!(
+                "step",
+                number = i + 1,
+                direction = direction,
+                to = (to.x, to.y)
+            );
+        }
+
+        steps.into_iter().map(|(position, _)| position).collect()
+    }
+
+    // Every position on the loop from `start` round to just before it, with the way out
+    // of each, or the position of the pipe where it breaks off
+    fn walk_loop(&self, start: &Position) -> Result<Vec<(Position, Direction)>, Position> {
+        let mut path = Vec::new();
+
+        let mut current_pos = *start;
+        let mut entered_from = None;
+
+        loop {
+            let MapCell::Pipe(pipe) = self.layout[current_pos] else {
+                unreachable!("only pipes are walked onto");
+            };
+
+            let next_direction = pipe.get_next_direction(entered_from);
+            let next_pos = current_pos + next_direction.as_offset();
+            path.push((current_pos, next_direction));
+
+            let connects_back = match self.layout.get(&next_pos) {
+                Some(MapCell::Pipe(next)) => {
+                    next.as_mask() & as_mask(&next_direction.opposite()) != 0
+                }
+                _ => false,
+            };
+
+            if !connects_back {
+                return Err(current_pos);
+            }
+
+            current_pos = next_pos;
+            entered_from = Some(next_direction.opposite());
+
+            if current_pos == *start {
+                return Ok(path);
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.layout.rows() {
+            for cell in row {
+                match cell {
+                    MapCell::Empty => write!(f, ".")?,
+                    MapCell::Pipe(Pipe(Direction::Left, Direction::Right)) => write!(f, "-")?,
+                    MapCell::Pipe(Pipe(Direction::Up, Direction::Down)) => write!(f, "|")?,
+                    MapCell::Pipe(Pipe(Direction::Up, Direction::Right)) => write!(f, "╰")?,
+                    MapCell::Pipe(Pipe(Direction::Down, Direction::Right)) => write!(f, "╭")?,
+                    MapCell::Pipe(Pipe(Direction::Down, Direction::Left)) => write!(f, "╮")?,
+                    MapCell::Pipe(Pipe(Direction::Up, Direction::Left)) => write!(f, "╯")?,
+                    _ => unreachable!(),
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+    const INPUT_TEST_PART2: &str = include_str!("../input_test_part2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::solve_part1(INPUT_TEST).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::solve_part2(INPUT_TEST_PART2).unwrap(), 10);
+    }
+
+    #[test]
+    fn reports_broken_loops() {
+        // Cut off below the loop, which then leads off the bottom of the map
+        let first_four = INPUT_TEST.lines().take(4).collect::<Vec<_>>().join("\n");
+        let error = Day10::parse(&first_four).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let error = Day10::parse("S-7\n|.|\nL-.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day10>(INPUT_TEST);
+        aoc_common::generate::assert_truncated_inputs_solve::<Day10>(INPUT_TEST_PART2);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day10>(30);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day10::Day10;
+
+fn main() {
+    aoc_common::solution::main::<Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,22 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day11;
+
+// `size` is the side of the image. Some rows and columns are left empty for expanding
+impl Generate for Day11 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let empty_rows = (0..size).map(|_| rng.gen_ratio(1, 10)).collect::<Vec<_>>();
+        let empty_columns = (0..size).map(|_| rng.gen_ratio(1, 10)).collect::<Vec<_>>();
+
+        generate::grid(rng, size, size, |rng, x, y| {
+            if !empty_rows[y] && !empty_columns[x] && rng.gen_ratio(1, 40) {
+                '#'
+            } else {
+                '.'
+            }
+        })
+    }
+}
@@ -0,0 +1,140 @@
+mod generate;
+
+use std::iter;
+
+use aoc_common::{
+    geometry::Position,
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed<'input> = Image;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        generate_image(input)
+    }
+
+    fn part1(image: &Self::Parsed<'_>) -> Answer {
+        solve::<1>(image).into()
+    }
+
+    fn part2(image: &Self::Parsed<'_>) -> Answer {
+        solve::<999_999>(image).into()
+    }
+}
+
+fn solve<const EXPANSION: usize>(image: &Image) -> usize {
+    let galaxies = expand(&image.galaxies, &image.empties, EXPANSION as i64);
+
+    for (i, (from, to)) in image.galaxies.iter().zip(&galaxies).enumerate() {
+        trace!(
+            "galaxy",
+            number = i + 1,
+            from = (from.x, from.y),
+            to = (to.x, to.y)
+        );
+    }
+
+    sum_distance_pairs(&galaxies)
+}
+
+pub struct Image {
+    galaxies: Vec<Position>,
+    empties: Empties,
+}
+
+struct Empties {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+}
+
+fn generate_image(input: &str) -> Result<Image, ParseError> {
+    let grid = Grid::try_parse(input, "`.` or `#`", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+
+    let image = grid
+        .iter()
+        .filter(|(_, is_galaxy)| **is_galaxy)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+
+    let xs = (0..grid.width() as i64)
+        .filter(|x| image.iter().all(|galaxy| galaxy.x != *x))
+        .collect();
+
+    let ys = (0..grid.height() as i64)
+        .filter(|y| image.iter().all(|galaxy| galaxy.y != *y))
+        .collect();
+
+    Ok(Image {
+        galaxies: image,
+        empties: Empties { xs, ys },
+    })
+}
+
+fn expand(image: &[Position], empties: &Empties, amount: i64) -> Vec<Position> {
+    image
+        .iter()
+        .map(|galaxy| Position {
+            x: galaxy.x + empties.xs.iter().filter(|x| **x < galaxy.x).count() as i64 * amount,
+            y: galaxy.y + empties.ys.iter().filter(|y| **y < galaxy.y).count() as i64 * amount,
+        })
+        .collect()
+}
+
+fn sum_distance_pairs(image: &[Position]) -> usize {
+    image
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| image.iter().skip(i + 1).zip(iter::repeat(a)))
+        .map(|(a, b)| a.manhattan_distance(b) as usize)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::solve_part1(INPUT_TEST).unwrap(), 374);
+    }
+
+    #[test]
+    fn expansion_by_10_example() {
+        assert_eq!(solve::<9>(&Day11::parse(INPUT_TEST).unwrap()), 1030);
+    }
+
+    #[test]
+    fn expansion_by_100_example() {
+        assert_eq!(solve::<99>(&Day11::parse(INPUT_TEST).unwrap()), 8410);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day11>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day11>(40);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
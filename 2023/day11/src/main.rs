@@ -0,0 +1,5 @@
+use advent_of_code_2023_day11::Day11;
+
+fn main() {
+    aoc_common::solution::main::<Day11>(env!("CARGO_MANIFEST_DIR"));
+}
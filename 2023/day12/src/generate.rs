@@ -0,0 +1,42 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day12;
+
+// `size` is the number of records. Each is made from a real arrangement of up to 20
+// springs, with some of them then hidden, so that there's always at least one way to
+// arrange them
+impl Generate for Day12 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let length = rng.gen_range(1..=20);
+            let mut damaged = (0..length).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+            let at = rng.gen_range(0..length);
+            damaged[at] = true;
+
+            let groups = damaged
+                .split(|damaged| !damaged)
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+
+            let springs = damaged
+                .iter()
+                .map(|damaged| match damaged {
+                    _ if rng.gen_ratio(2, 5) => '?',
+                    true => '#',
+                    false => '.',
+                })
+                .collect::<String>();
+
+            input.push_str(&format!("{springs} {}\n", groups.join(",")));
+        }
+
+        input
+    }
+}
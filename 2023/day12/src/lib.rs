@@ -1,56 +1,65 @@
-use std::collections::HashMap;
-
-const INPUT_TEST: &str = include_str!("../input_test.txt");
-const INPUT: &str = include_str!("../input.txt");
+mod generate;
+#[cfg(test)]
+mod reference;
 
-fn main() {
-    let test_result = solve_part1(INPUT_TEST);
-    println!("Test Part 1: {}", test_result);
-    assert!(test_result == 21);
+use std::collections::HashMap;
 
-    let part_1_result = solve_part1(INPUT);
-    println!("Part 1: {}", part_1_result);
+use aoc_common::{
+    parallel,
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
 
-    let test_result = solve_part2(INPUT_TEST);
-    println!("Test Part 2: {}", test_result);
-    assert!(test_result == 525152);
+pub struct Day12;
 
-    let part_2_result = solve_part2(INPUT);
-    println!("Part 2: {}", part_2_result);
-}
+impl Solution for Day12 {
+    const DAY: u8 = 12;
 
-fn solve_part1(input: &str) -> usize {
-    let lines = input.lines();
+    type Parsed<'input> = Vec<ConditionRecord>;
 
-    lines
-        .map(parse_record)
-        .map(|record| count_combinations(record, &mut Default::default()))
-        .sum()
-}
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input).map(parse_record).collect()
+    }
 
-fn solve_part2(input: &str) -> usize {
-    let lines = input.lines();
+    fn part1(records: &Self::Parsed<'_>) -> Answer {
+        records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let count = count_combinations(record.clone(), &mut Default::default());
+                trace!("record", number = i + 1, arrangements = count);
+                count
+            })
+            .sum::<usize>()
+            .into()
+    }
 
-    lines
-        .map(parse_record)
-        .map(|record| expand_record_for_part2(record, 5))
-        .map(|record| count_combinations(record, &mut Default::default()))
-        .sum()
+    fn part2(records: &Self::Parsed<'_>) -> Answer {
+        let numbered = records.iter().enumerate().collect::<Vec<_>>();
+
+        parallel::map(&numbered, |(i, record)| {
+            let count =
+                count_combinations(expand_record_for_part2(record, 5), &mut Default::default());
+            trace!("record", number = i + 1, arrangements = count);
+            count
+        })
+        .into_iter()
+        .sum::<usize>()
+        .into()
+    }
 }
 
-fn parse_record(line: &str) -> ConditionRecord {
-    let (springs, damaged_groups) = line.split_once(' ').unwrap();
+fn parse_record(line: Line<'_>) -> Result<ConditionRecord, ParseError> {
+    let (springs, damaged_groups) = line.split_once(line.text, " ")?;
 
-    let springs = springs.chars().map(|c| c.into()).collect();
-    let damaged_groups = damaged_groups
-        .split(',')
-        .map(|n| n.parse().unwrap())
-        .collect();
+    let springs = line.parse_chars(springs, "a spring, one of `.#?`", Spring::from_char)?;
+    let damaged_groups = line.parse_list(damaged_groups, ",", "a group size")?;
 
-    ConditionRecord {
+    Ok(ConditionRecord {
         springs,
         damaged_groups,
-    }
+    })
 }
 
 fn count_combinations(
@@ -67,11 +76,7 @@ fn count_combinations(
             // If there are no more remaining, then as long as the remaining springs
             // are all operational, or unknown, then we have a valid combination.
             let Some(_) = record.damaged_groups.first() else {
-                if record
-                    .springs
-                    .iter()
-                    .any(|spring| *spring == Spring::Damaged)
-                {
+                if record.springs.contains(&Spring::Damaged) {
                     return 0;
                 } else {
                     return 1;
@@ -85,7 +90,7 @@ fn count_combinations(
             };
 
             // Since we haven't run out, we can move onto the next spring
-            return match next_spring {
+            match next_spring {
                 Spring::Operational => count_combinations(
                     ConditionRecord {
                         springs: record.springs[1..].into(),
@@ -105,7 +110,7 @@ fn count_combinations(
                         memo,
                     ) + count_with_damaged(record, memo)
                 }
-            };
+            }
         })();
 
         memo.insert(key, res);
@@ -162,7 +167,7 @@ fn count_with_damaged(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct ConditionRecord {
+pub struct ConditionRecord {
     springs: Vec<Spring>,
     damaged_groups: Vec<usize>,
 }
@@ -174,18 +179,18 @@ enum Spring {
     Unknown,
 }
 
-impl From<char> for Spring {
-    fn from(c: char) -> Self {
+impl Spring {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '.' => Spring::Operational,
-            '#' => Spring::Damaged,
-            '?' => Spring::Unknown,
-            _ => unreachable!(),
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
         }
     }
 }
 
-fn expand_record_for_part2(record: ConditionRecord, repetitions: usize) -> ConditionRecord {
+fn expand_record_for_part2(record: &ConditionRecord, repetitions: usize) -> ConditionRecord {
     let mut springs = Vec::with_capacity(repetitions * record.springs.len() + repetitions - 1);
 
     for _ in 0..repetitions - 1 {
@@ -206,3 +211,41 @@ fn expand_record_for_part2(record: ConditionRecord, repetitions: usize) -> Condi
         damaged_groups,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::solve_part1(INPUT_TEST).unwrap(), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day12::solve_part2(INPUT_TEST).unwrap(), 525_152);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day12>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day12>(100);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day12>(0..20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day12::Day12;
+
+fn main() {
+    aoc_common::solution::main::<Day12>(env!("CARGO_MANIFEST_DIR"));
+}
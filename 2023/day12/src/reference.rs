@@ -0,0 +1,57 @@
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day12;
+
+// Tries every way of filling in the unknown springs, and counts those that give the
+// right groups. Unfolded records have far too many unknowns for that, so there's no
+// reference for part 2; but it counts with the same code as part 1
+impl Reference for Day12 {
+    const REFERENCE_SIZE: usize = 20;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        if part == Part::Two {
+            return Answer::Unavailable;
+        }
+
+        input
+            .lines()
+            .map(|line| {
+                let (springs, groups) = line.split_once(' ').unwrap();
+                let unknowns = springs.matches('?').count();
+
+                (0..1u32 << unknowns)
+                    .filter(|damaged| {
+                        let mut unknown = 0;
+
+                        let filled = springs
+                            .chars()
+                            .map(|spring| match spring {
+                                '?' => {
+                                    unknown += 1;
+                                    if damaged & (1 << (unknown - 1)) != 0 {
+                                        '#'
+                                    } else {
+                                        '.'
+                                    }
+                                }
+                                spring => spring,
+                            })
+                            .collect::<String>();
+
+                        let filled_groups = filled
+                            .split('.')
+                            .filter(|group| !group.is_empty())
+                            .map(|group| group.len().to_string())
+                            .collect::<Vec<_>>();
+
+                        filled_groups.join(",") == groups
+                    })
+                    .count()
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day13;
+
+// `size` is the number of patterns. Each has exactly one line of reflection, and
+// exactly one other line that would be a reflection but for a single smudge
+impl Generate for Day13 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| pattern(rng))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn pattern(rng: &mut StdRng) -> String {
+    loop {
+        let width = rng.gen_range(5..=17);
+        let height = rng.gen_range(5..=17);
+
+        // Cells that have to match end up in the same class
+        let mut classes = Classes::new(width * height);
+        let cell = |x: usize, y: usize| y * width + x;
+
+        let clean = mirrored_pairs(rng, width, height);
+        let smudged = mirrored_pairs(rng, width, height);
+
+        for &((ax, ay), (bx, by)) in &clean {
+            classes.join(cell(ax, ay), cell(bx, by));
+        }
+
+        let smudge = rng.gen_range(0..smudged.len());
+
+        for &((ax, ay), (bx, by)) in &smudged[..smudge] {
+            classes.join(cell(ax, ay), cell(bx, by));
+        }
+
+        for &((ax, ay), (bx, by)) in &smudged[smudge + 1..] {
+            classes.join(cell(ax, ay), cell(bx, by));
+        }
+
+        // The smudged pair has to differ, which it can't if they're in the same class
+        let ((ax, ay), (bx, by)) = smudged[smudge];
+        let (a, b) = (classes.find(cell(ax, ay)), classes.find(cell(bx, by)));
+
+        if a == b {
+            continue;
+        }
+
+        let mut rocks = (0..width * height)
+            .map(|_| rng.gen())
+            .collect::<Vec<bool>>();
+        rocks[b] = !rocks[a];
+
+        let rows = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| rocks[classes.find(cell(x, y))])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Anything else lining up by chance would make the pattern ambiguous
+        if reflections(&rows, 0) != 1 || reflections(&rows, 1) != 1 {
+            continue;
+        }
+
+        return rows
+            .iter()
+            .map(|row| {
+                let mut row = row
+                    .iter()
+                    .map(|rock| if *rock { '#' } else { '.' })
+                    .collect::<String>();
+                row.push('\n');
+                row
+            })
+            .collect();
+    }
+}
+
+type Cell = (usize, usize);
+
+// The pairs of cells that match across a random line of reflection
+fn mirrored_pairs(rng: &mut StdRng, width: usize, height: usize) -> Vec<(Cell, Cell)> {
+    let vertical = rng.gen();
+    let (across, along) = if vertical {
+        (width, height)
+    } else {
+        (height, width)
+    };
+
+    // The line is between `line - 1` and `line`
+    let line = rng.gen_range(1..across);
+    let reach = line.min(across - line);
+
+    let mut pairs = (0..reach)
+        .flat_map(|i| (0..along).map(move |j| (line - 1 - i, line + i, j)))
+        .map(|(a, b, j)| {
+            if vertical {
+                ((a, j), (b, j))
+            } else {
+                ((j, a), (j, b))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    pairs.shuffle(rng);
+    pairs
+}
+
+// How many lines of reflection, vertical or horizontal, have exactly `smudges` cells
+// that don't match
+fn reflections(rows: &[Vec<bool>], smudges: usize) -> usize {
+    let columns = (0..rows[0].len())
+        .map(|x| rows.iter().map(|row| row[x]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    [rows, &columns]
+        .iter()
+        .map(|lines| {
+            (1..lines.len())
+                .filter(|line| {
+                    let differences = lines[..*line]
+                        .iter()
+                        .rev()
+                        .zip(&lines[*line..])
+                        .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+                        .sum::<usize>();
+
+                    differences == smudges
+                })
+                .count()
+        })
+        .sum()
+}
+
+// Union-find over the cells
+struct Classes {
+    parents: Vec<usize>,
+}
+
+impl Classes {
+    fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+        }
+    }
+
+    fn find(&mut self, cell: usize) -> usize {
+        let parent = self.parents[cell];
+
+        if parent == cell {
+            return cell;
+        }
+
+        let root = self.find(parent);
+        self.parents[cell] = root;
+        root
+    }
+
+    fn join(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+    }
+}
@@ -0,0 +1,162 @@
+mod generate;
+
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    // Each pattern as bitmasks of its rows and of its columns
+    type Parsed<'input> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::blocks(input)
+            .map(|block| parse_pattern(block?))
+            .collect()
+    }
+
+    fn part1(patterns: &Self::Parsed<'_>) -> Answer {
+        summarise::<0>(patterns).into()
+    }
+
+    fn part2(patterns: &Self::Parsed<'_>) -> Answer {
+        summarise::<1>(patterns).into()
+    }
+}
+
+fn summarise<const DIFFS: u32>(patterns: &[(Vec<u64>, Vec<u64>)]) -> usize {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(i, (rows, columns))| {
+            let rows_above = find_reflection_index::<DIFFS>(rows).map(|i| i + 1);
+            let columns_left = find_reflection_index::<DIFFS>(columns).map(|i| i + 1);
+
+            trace!(
+                "pattern",
+                number = i + 1,
+                rows_above = rows_above,
+                columns_left = columns_left
+            );
+
+            100 * rows_above.unwrap_or(0) + columns_left.unwrap_or(0)
+        })
+        .sum()
+}
+
+// Rows and columns are stored as bitmasks
+const MAX_PATTERN_SIZE: usize = u64::BITS as usize;
+
+type Pattern = (Vec<u64>, Vec<u64>);
+
+fn parse_pattern(lines: Vec<Line<'_>>) -> Result<Pattern, ParseError> {
+    let cells = parse::char_rows(lines.iter().copied(), "`.` or `#`", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+    let width = cells[0].len();
+
+    let oversized = lines
+        .get(MAX_PATTERN_SIZE)
+        .or_else(|| (width > MAX_PATTERN_SIZE).then_some(&lines[0]));
+
+    if let Some(line) = oversized {
+        return Err(line.error(
+            line.text,
+            format!("patterns can be at most {MAX_PATTERN_SIZE} cells in each direction"),
+        ));
+    }
+
+    let rows = cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, is_rock)| **is_rock)
+                .fold(0u64, |row, (i, _)| row | 1 << i)
+        })
+        .collect::<Vec<_>>();
+    let columns = transpose(&rows, width);
+
+    Ok((rows, columns))
+}
+
+fn transpose(rows: &[u64], width: usize) -> Vec<u64> {
+    let mut columns = vec![0u64; width];
+
+    for (r, row) in rows.iter().enumerate() {
+        for (c, column) in columns.iter_mut().enumerate() {
+            *column |= ((row >> c) & 1) << r;
+        }
+    }
+
+    columns
+}
+
+fn find_reflection_index<const DIFFS: u32>(input: &[u64]) -> Option<usize> {
+    for i in 0..input.len() - 1 {
+        let mut ii = i;
+        let mut jj = i + 1;
+        let mut diffs = 0u32;
+
+        while jj < input.len() {
+            diffs += (input[ii] ^ input[jj]).count_ones();
+
+            if diffs > DIFFS {
+                break;
+            }
+
+            if ii == 0 {
+                break;
+            }
+
+            ii -= 1;
+            jj += 1;
+        }
+
+        if diffs == DIFFS {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::solve_part1(INPUT_TEST).unwrap(), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day13::solve_part2(INPUT_TEST).unwrap(), 400);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day13>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day13>(20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day13::Day13;
+
+fn main() {
+    aoc_common::solution::main::<Day13>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,15 @@
+use aoc_common::generate::{self, Generate};
+use rand::rngs::StdRng;
+
+use crate::Day14;
+
+// `size` is the side of the platform
+impl Generate for Day14 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate::grid(rng, size, size, |rng, _, _| {
+            generate::weighted(rng, &[('.', 11), ('O', 5), ('#', 4)])
+        })
+    }
+}
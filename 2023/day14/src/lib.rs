@@ -0,0 +1,224 @@
+mod generate;
+
+use std::fmt::{self, Display, Formatter};
+
+use aoc_common::{
+    cycle,
+    geometry::Position,
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed<'input> = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_platform(input)
+    }
+
+    fn part1(platform: &Self::Parsed<'_>) -> Answer {
+        let mut platform = platform.clone();
+
+        platform.tilt_north();
+
+        for y in 0..platform.height() {
+            let rocks = (0..platform.width())
+                .filter(|&x| platform.cells[Position::from((x, y))] == CellContents::RoundedRock)
+                .count();
+
+            trace!(
+                "row",
+                number = y + 1,
+                rocks = rocks,
+                load = rocks * (platform.height() - y)
+            );
+        }
+
+        platform.calculate_weight().into()
+    }
+
+    fn part2(platform: &Self::Parsed<'_>) -> Answer {
+        let mut cycles = 0;
+
+        // The platform soon settles into a loop, which can be skipped round
+        cycle::state_after(platform.clone(), 1_000_000_000, |platform| {
+            platform.cycle();
+            cycles += 1;
+
+            trace!("cycle", number = cycles, load = platform.calculate_weight());
+        })
+        .calculate_weight()
+        .into()
+    }
+}
+
+fn parse_platform(input: &str) -> Result<Platform, ParseError> {
+    Ok(Platform {
+        cells: Grid::try_parse(input, "`.`, `O` or `#`", |c| match c {
+            '.' => Some(CellContents::Empty),
+            'O' => Some(CellContents::RoundedRock),
+            '#' => Some(CellContents::CubeShapedRock),
+            _ => None,
+        })?,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    cells: Grid<CellContents>,
+}
+
+impl Platform {
+    fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    // Moves a rounded rock from one cell into its neighbour if there's space for it
+    fn roll(&mut self, from: (usize, usize), to: (usize, usize)) {
+        let from = Position::from(from);
+        let to = Position::from(to);
+
+        if self.cells[to] == CellContents::Empty && self.cells[from] == CellContents::RoundedRock {
+            self.cells.swap(&from, &to);
+        }
+    }
+
+    fn tilt_north(&mut self) {
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                for yy in 0..self.height() - y - 1 {
+                    self.roll((x, yy + 1), (x, yy));
+                }
+            }
+        }
+    }
+
+    fn calculate_weight(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| **cell == CellContents::RoundedRock)
+            .map(|(position, _)| self.height() - position.y as usize)
+            .sum()
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl Display for CellContents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CellContents::Empty => write!(f, "."),
+            CellContents::RoundedRock => write!(f, "O"),
+            CellContents::CubeShapedRock => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CellContents {
+    Empty,
+    RoundedRock,
+    CubeShapedRock,
+}
+
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Platform {
+    fn cycle(&mut self) {
+        self.tilt(Direction::North);
+        self.tilt(Direction::West);
+        self.tilt(Direction::South);
+        self.tilt(Direction::East);
+    }
+
+    fn tilt(&mut self, direction: Direction) {
+        match direction {
+            Direction::North => self.tilt_north(),
+            Direction::West => self.tilt_west(),
+            Direction::South => self.tilt_south(),
+            Direction::East => self.tilt_east(),
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for x in 0..self.width() {
+            for y in (0..self.height()).rev() {
+                for yy in (1..self.height() - y).rev() {
+                    self.roll((x, yy - 1), (x, yy));
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                for xx in 0..self.width() - x - 1 {
+                    self.roll((xx + 1, y), (xx, y));
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for y in 0..self.height() {
+            for x in (0..self.width()).rev() {
+                for xx in (1..self.width() - x).rev() {
+                    self.roll((xx - 1, y), (xx, y));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14::solve_part1(INPUT_TEST).unwrap(), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day14::solve_part2(INPUT_TEST).unwrap(), 64);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day14>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day14>(30);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day14::Day14;
+
+fn main() {
+    aoc_common::solution::main::<Day14>(env!("CARGO_MANIFEST_DIR"));
+}
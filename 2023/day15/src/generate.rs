@@ -0,0 +1,33 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day15;
+
+// `size` is the number of steps. They share a limited set of labels, so that lenses
+// get replaced and removed as well as added
+impl Generate for Day15 {
+    const DEFAULT_SIZE: usize = 4000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let labels = (0..size / 8 + 1)
+            .map(|_| {
+                let len = rng.gen_range(2..=6);
+                generate::names(rng, 1, len).remove(0)
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..size)
+            .map(|_| {
+                let label = labels.choose(rng).unwrap();
+
+                if rng.gen_ratio(1, 3) {
+                    format!("{label}-")
+                } else {
+                    format!("{label}={}", rng.gen_range(1..=9))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        steps.join(",") + "\n"
+    }
+}
@@ -0,0 +1,189 @@
+mod generate;
+
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed<'input> = Vec<Step<'input>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "expected a list of steps"))?;
+
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(extra.text, "expected all of the steps on a single line"));
+        }
+
+        line.text
+            .split(',')
+            .map(|text| {
+                Ok(Step {
+                    text,
+                    command: parse_command(&line, text)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> Answer {
+        steps
+            .iter()
+            .map(|step| {
+                let hash = calculate_hash(step.text);
+                trace!("step", text = step.text, hash = hash);
+                hash as usize
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> Answer {
+        steps
+            .iter()
+            .map(|step| step.command)
+            .fold(vec![vec![]; 256], apply_command)
+            .iter()
+            .enumerate()
+            .map(|(i, bucket)| {
+                (i + 1)
+                    * bucket
+                        .iter()
+                        .enumerate()
+                        .map(|(j, (_, focal_length))| (j + 1) * (*focal_length as usize))
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn calculate_hash(input: &str) -> u8 {
+    input
+        .chars()
+        .map(|c| c as u8)
+        .fold(0_u8, |agg, c| agg.wrapping_add(c).wrapping_mul(17))
+}
+
+pub struct Step<'a> {
+    text: &'a str,
+    command: Command<'a>,
+}
+
+fn parse_command<'a>(line: &Line<'a>, input: &'a str) -> Result<Command<'a>, ParseError> {
+    let label_length = input
+        .find(['=', '-'])
+        .ok_or_else(|| line.error(input, "expected a step containing `=` or `-`"))?;
+
+    let label = &input[0..label_length];
+    let label_hash = calculate_hash(label);
+
+    if input.as_bytes()[label_length] == b'-' {
+        let rest = &input[label_length + 1..];
+
+        if !rest.is_empty() {
+            return Err(line.error(rest, "expected nothing after `-`"));
+        }
+
+        Ok(Command::Remove(label_hash, label))
+    } else {
+        let focal_length = line.parse(&input[label_length + 1..], "a focal length")?;
+
+        Ok(Command::Append(label_hash, label, focal_length))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Command<'a> {
+    Remove(u8, &'a str),
+    Append(u8, &'a str, u8),
+}
+
+fn apply_command<'a>(
+    hashmap: Vec<Vec<(&'a str, u8)>>,
+    command: Command<'a>,
+) -> Vec<Vec<(&'a str, u8)>> {
+    let mut hashmap = hashmap;
+
+    let (label_hash, label) = match command {
+        Command::Remove(label_hash, label) | Command::Append(label_hash, label, _) => {
+            (label_hash, label)
+        }
+    };
+
+    match command {
+        Command::Remove(label_hash, label) => {
+            let bucket = &mut hashmap[label_hash as usize];
+
+            if let Some(index) = bucket
+                .iter()
+                .position(|(this_label, _)| this_label == &label)
+            {
+                bucket.remove(index);
+            }
+        }
+        Command::Append(label_hash, label, focal_length) => {
+            let bucket = &mut hashmap[label_hash as usize];
+
+            if let Some(entry) = bucket
+                .iter_mut()
+                .find(|(this_label, _)| this_label == &label)
+            {
+                entry.1 = focal_length;
+            } else {
+                bucket.push((label, focal_length));
+            }
+        }
+    }
+
+    trace!(
+        "step",
+        label = label,
+        bucket = label_hash,
+        lenses = hashmap[label_hash as usize]
+    );
+
+    hashmap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day15::solve_part1(INPUT_TEST).unwrap(), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day15::solve_part2(INPUT_TEST).unwrap(), 145);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day15>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day15>(200);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day15::Day15;
+
+fn main() {
+    aoc_common::solution::main::<Day15>(env!("CARGO_MANIFEST_DIR"));
+}
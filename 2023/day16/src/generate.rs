@@ -0,0 +1,15 @@
+use aoc_common::generate::{self, Generate};
+use rand::rngs::StdRng;
+
+use crate::Day16;
+
+// `size` is the side of the contraption
+impl Generate for Day16 {
+    const DEFAULT_SIZE: usize = 110;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate::grid(rng, size, size, |rng, _, _| {
+            generate::weighted(rng, &[('.', 40), ('/', 1), ('\\', 1), ('|', 1), ('-', 1)])
+        })
+    }
+}
@@ -0,0 +1,218 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    geometry::{Direction, Position},
+    grid::Grid,
+    parallel,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed<'input> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Contraption::try_from(input)
+    }
+
+    fn part1(contraption: &Self::Parsed<'_>) -> Answer {
+        let trace_result = trace(contraption, Position::ORIGIN, Direction::Right);
+
+        trace_result.count_energized().into()
+    }
+
+    fn part2(contraption: &Self::Parsed<'_>) -> Answer {
+        let width = contraption.grid.width() as i64;
+        let height = contraption.grid.height() as i64;
+
+        let top = (0..width).map(|x| (Position { x, y: 0 }, Direction::Down));
+        let bottom = (0..width).map(|x| (Position { x, y: height - 1 }, Direction::Up));
+        let left = (0..height).map(|y| (Position { x: 0, y }, Direction::Right));
+        let right = (0..height).map(|y| (Position { x: width - 1, y }, Direction::Left));
+
+        let entry_points = top
+            .chain(bottom)
+            .chain(left)
+            .chain(right)
+            .collect::<Vec<_>>();
+
+        parallel::map(&entry_points, |(position, direction)| {
+            let energized = trace(contraption, *position, *direction).count_energized();
+
+            trace!(
+                "entry",
+                at = (position.x, position.y),
+                direction = direction,
+                energized = energized
+            );
+            energized
+        })
+        .into_iter()
+        .max()
+        .unwrap()
+        .into()
+    }
+}
+
+pub struct Contraption {
+    grid: Grid<Option<Mirror>>,
+}
+
+impl TryFrom<&str> for Contraption {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::try_parse(input, "`.` or a mirror, one of `/\\|-`", |c| match c {
+            '/' => Some(Some(Mirror::ForwardSlash)),
+            '\\' => Some(Some(Mirror::BackSlash)),
+            '|' => Some(Some(Mirror::Vertical)),
+            '-' => Some(Some(Mirror::Horizontal)),
+            '.' => Some(None),
+            _ => None,
+        })?;
+
+        Ok(Self { grid })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Mirror {
+    ForwardSlash,
+    BackSlash,
+    Vertical,
+    Horizontal,
+}
+
+struct TraceResult {
+    visits: Grid<bool>,
+}
+
+impl TraceResult {
+    fn count_energized(&self) -> usize {
+        self.visits.iter().filter(|(_, &v)| v).count()
+    }
+}
+
+fn trace(contraption: &Contraption, position: Position, direction: Direction) -> TraceResult {
+    let grid = &contraption.grid;
+
+    let mut trace_result = TraceResult {
+        visits: Grid::filled(grid.width(), grid.height(), false),
+    };
+
+    let mut visited = HashSet::new();
+    let mut stack = Vec::new();
+
+    stack.push((position, direction));
+
+    while let Some((position, direction)) = stack.pop() {
+        if !visited.insert((position, direction)) {
+            continue;
+        }
+
+        trace!("beam", at = (position.x, position.y), direction = direction);
+
+        macro_rules! maybe_push {
+            ($next_direction: expr) => {
+                let next_position = position + $next_direction.as_offset();
+
+                if grid.is_within_bounds(&next_position) {
+                    stack.push((next_position, $next_direction));
+                }
+            };
+        }
+
+        trace_result.visits[position] = true;
+
+        if let Some(mirror) = &grid[position] {
+            let (next_direction, maybe_fork_direction) = mirror.get_outputs(&direction);
+
+            maybe_push!(next_direction);
+
+            if let Some(fork_direction) = maybe_fork_direction {
+                maybe_push!(fork_direction);
+            }
+        } else {
+            maybe_push!(direction);
+        }
+    }
+
+    trace_result
+}
+
+impl Mirror {
+    fn get_outputs(&self, input: &Direction) -> (Direction, Option<Direction>) {
+        match self {
+            Mirror::ForwardSlash => match input {
+                Direction::Up => (Direction::Right, None),
+                Direction::Down => (Direction::Left, None),
+                Direction::Left => (Direction::Down, None),
+                Direction::Right => (Direction::Up, None),
+            },
+            Mirror::BackSlash => match input {
+                Direction::Up => (Direction::Left, None),
+                Direction::Down => (Direction::Right, None),
+                Direction::Left => (Direction::Up, None),
+                Direction::Right => (Direction::Down, None),
+            },
+            Mirror::Vertical => match input {
+                Direction::Up => (Direction::Up, None),
+                Direction::Down => (Direction::Down, None),
+                Direction::Left => (Direction::Up, Some(Direction::Down)),
+                Direction::Right => (Direction::Up, Some(Direction::Down)),
+            },
+            Mirror::Horizontal => match input {
+                Direction::Up => (Direction::Left, Some(Direction::Right)),
+                Direction::Down => (Direction::Left, Some(Direction::Right)),
+                Direction::Left => (Direction::Left, None),
+                Direction::Right => (Direction::Right, None),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day16::solve_part1(INPUT_TEST).unwrap(), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day16::solve_part2(INPUT_TEST).unwrap(), 51);
+    }
+
+    #[test]
+    fn reports_an_empty_contraption() {
+        assert!(Day16::parse("").is_err());
+        assert!(Day16::parse("\n\n").is_err());
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day16>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day16>(30);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day16>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
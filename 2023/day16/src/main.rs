@@ -0,0 +1,5 @@
+use advent_of_code_2023_day16::Day16;
+
+fn main() {
+    aoc_common::solution::main::<Day16>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day17;
+
+// `size` is the side of the map, at least 5 so that the ultra crucible can reach the
+// far corner
+impl Generate for Day17 {
+    const DEFAULT_SIZE: usize = 141;
+    const MIN_SIZE: usize = 5;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        generate::grid(rng, size, size, |rng, _, _| {
+            char::from_digit(rng.gen_range(1..=9), 10).unwrap()
+        })
+    }
+}
@@ -0,0 +1,178 @@
+mod generate;
+
+use aoc_common::{
+    geometry::{Direction, Position},
+    graph,
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed<'input> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Grid::try_parse(input, "a heat loss digit", |c| c.to_digit(10))
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Answer {
+        solve::<1, 3>(grid).map_or(Answer::Unavailable, Answer::from)
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Answer {
+        solve::<4, 10>(grid).map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+// `None` if the crucible can't reach the goal, as when it can't go MIN blocks in a
+// straight line
+fn solve<const MIN: usize, const MAX: usize>(grid: &Grid<u32>) -> Option<u32> {
+    distance_of_shortest_path_between::<MIN, MAX>(
+        grid,
+        &Position::ORIGIN,
+        &Position {
+            x: grid.width() as i64 - 1,
+            y: grid.height() as i64 - 1,
+        },
+    )
+}
+
+fn get_nexts<const MIN: usize, const MAX: usize>(
+    grid: &Grid<u32>,
+    position: &Position,
+    incoming: Option<(Direction, usize)>,
+) -> Vec<(Position, (Direction, usize), u32)> {
+    Direction::ALL
+        .iter()
+        // May not turn back on ourselves
+        .filter(|direction| incoming.is_none_or(|incoming| **direction != incoming.0.opposite()))
+        .filter_map(|direction| {
+            incoming.map_or(Some((direction, MIN - 1, MIN)), |incoming| {
+                if *direction != incoming.0 {
+                    // If we're going in a different direction, return default
+                    Some((direction, MIN - 1, MIN))
+                } else if incoming.1 + 1 < MAX {
+                    // If we're going in the same direction, may not be further than MAX
+                    // and also increment by one
+                    Some((direction, incoming.1 + 1, 1))
+                } else {
+                    // If we're going in the same direction and we're already at MAX,
+                    // we cannot go any further so discard
+                    None
+                }
+            })
+        })
+        .filter_map(|(direction, distance, steps)| {
+            let offset = direction.as_offset();
+
+            // Sum the cost of all the steps
+            let mut next_position = *position;
+            let mut cost_to_add = 0;
+            for n in 0..steps {
+                next_position = *position + offset * (n + 1) as i64;
+
+                // Ensure we're not going out of bounds
+                cost_to_add += grid.get(&next_position)?;
+            }
+            Some((next_position, (*direction, distance), cost_to_add))
+        })
+        .collect()
+}
+
+type State = (Position, Option<(Direction, usize)>);
+
+fn distance_of_shortest_path_between<const MIN: usize, const MAX: usize>(
+    grid: &Grid<u32>,
+    start: &Position,
+    goal: &Position,
+) -> Option<u32> {
+    let successors = |&(position, incoming): &State| {
+        get_nexts::<MIN, MAX>(grid, &position, incoming)
+            .into_iter()
+            .map(|(next_position, next_incoming, cost_to_add)| {
+                ((next_position, Some(next_incoming)), cost_to_add)
+            })
+    };
+
+    // Every block loses at least one heat, so the manhattan distance never overestimates
+    let heuristic = |(position, _): &State| position.manhattan_distance(goal) as u32;
+
+    graph::astar((*start, None), &successors, heuristic, |(position, _)| {
+        position == goal
+    })
+    .map(|path| {
+        let mut heat_loss = 0;
+
+        // A step goes MIN blocks at once after turning, so each block is added up
+        for (i, pair) in path.nodes.windows(2).enumerate() {
+            let [(from, _), (to, Some((direction, _)))] = pair else {
+                unreachable!("every step after the start has a direction");
+            };
+
+            for n in 1..=from.manhattan_distance(to) {
+                heat_loss += grid[*from + direction.as_offset() * n as i64];
+            }
+
+            trace!(
+                "step",
+                number = i + 1,
+                to = (to.x, to.y),
+                direction = direction,
+                heat_loss = heat_loss
+            );
+        }
+
+        path.cost
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day17::solve_part1(INPUT_TEST).unwrap(), 102);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day17::solve_part2(INPUT_TEST).unwrap(), 94);
+    }
+
+    #[test]
+    fn reports_an_empty_map() {
+        let error = Day17::parse("\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn no_path_has_no_answer() {
+        assert_eq!(Day17::solve_part1("12\n").unwrap(), 2);
+        assert_eq!(Day17::solve_part2("12\n").unwrap(), Answer::Unavailable);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day17>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day17>(20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day17>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day17::Day17;
+
+fn main() {
+    aoc_common::solution::main::<Day17>(env!("CARGO_MANIFEST_DIR"));
+}
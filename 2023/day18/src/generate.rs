@@ -0,0 +1,107 @@
+use aoc_common::{
+    generate::{self, Generate},
+    geometry::Direction,
+};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day18;
+
+// `size` is how many times the outline can change course across the lagoon, at least 2.
+// Both readings of the plan are loops that never cross or touch themselves: the
+// colours turn the same outline a random number of quarter turns, and stretch it much
+// further
+impl Generate for Day18 {
+    const DEFAULT_SIZE: usize = 300;
+    const MIN_SIZE: usize = 2;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let (start, steps) = generate::outline(rng, size);
+
+        // Runs of steps in the same direction, along with which gaps between the
+        // outline's columns or rows each one crosses
+        let mut runs: Vec<(Direction, Vec<usize>)> = Vec::new();
+        let (mut x, mut y) = (start.x as usize, start.y as usize);
+
+        for direction in steps {
+            let gap = match direction {
+                Direction::Up => {
+                    y -= 1;
+                    y
+                }
+                Direction::Down => {
+                    y += 1;
+                    y - 1
+                }
+                Direction::Left => {
+                    x -= 1;
+                    x
+                }
+                Direction::Right => {
+                    x += 1;
+                    x - 1
+                }
+            };
+
+            match runs.last_mut() {
+                Some((last, gaps)) if *last == direction => gaps.push(gap),
+                _ => runs.push((direction, vec![gap])),
+            }
+        }
+
+        // Starting partway along a run would split it in two
+        if runs.len() > 1 && runs[0].0 == runs[runs.len() - 1].0 {
+            let (_, gaps) = runs.remove(0);
+            runs.last_mut().unwrap().1.extend(gaps);
+        }
+
+        // Every gap has its own width, so that the outline still closes once stretched
+        let mut widths = |max| {
+            (0..size)
+                .map(|_| rng.gen_range(1..=max))
+                .collect::<Vec<u64>>()
+        };
+        let [short_columns, short_rows] = [widths(10), widths(10)];
+        let max_long = 0xfffff / size as u64;
+        let [long_columns, long_rows] = [widths(max_long), widths(max_long)];
+
+        let quarter_turns = rng.gen_range(0..4);
+
+        let mut input = String::new();
+
+        for (direction, gaps) in runs {
+            let length = |columns: &[u64], rows: &[u64]| -> u64 {
+                let widths = if direction.is_vertical() {
+                    rows
+                } else {
+                    columns
+                };
+                gaps.iter().map(|gap| widths[*gap]).sum()
+            };
+
+            let turned = (0..quarter_turns).fold(direction, |direction, _| direction.turn_right());
+            let colour = format!(
+                "{:05x}{}",
+                length(&long_columns, &long_rows),
+                match turned {
+                    Direction::Right => 0,
+                    Direction::Down => 1,
+                    Direction::Left => 2,
+                    Direction::Up => 3,
+                }
+            );
+
+            input.push_str(&format!(
+                "{} {} (#{colour})\n",
+                match direction {
+                    Direction::Up => 'U',
+                    Direction::Down => 'D',
+                    Direction::Left => 'L',
+                    Direction::Right => 'R',
+                },
+                length(&short_columns, &short_rows)
+            ));
+        }
+
+        input
+    }
+}
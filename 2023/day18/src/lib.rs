@@ -0,0 +1,185 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use std::iter;
+
+use aoc_common::{
+    geometry::{Direction, Position},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed<'input> = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(DigPlan {
+            part1: parse_commands::<Part1Strategy>(input)?,
+            part2: parse_commands::<Part2Strategy>(input)?,
+        })
+    }
+
+    fn part1(plan: &Self::Parsed<'_>) -> Answer {
+        solve(&plan.part1).into()
+    }
+
+    fn part2(plan: &Self::Parsed<'_>) -> Answer {
+        solve(&plan.part2).into()
+    }
+}
+
+// The plan reads differently depending on whether the colour is treated as the instruction
+pub struct DigPlan {
+    part1: Vec<Command>,
+    part2: Vec<Command>,
+}
+
+fn parse_commands<S: ParseCommandsStrategy>(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input).map(S::parse_command).collect()
+}
+
+fn solve(commands: &[Command]) -> u64 {
+    let mut path_commands = commands.iter();
+    let mut position = Position::ORIGIN;
+
+    let path = iter::once(position)
+        .chain(iter::from_fn(|| {
+            if let Some(command) = path_commands.next() {
+                let offset = command.direction.as_offset() * command.count as i64;
+                position += offset;
+
+                trace!(
+                    "dig",
+                    direction = command.direction,
+                    count = command.count,
+                    to = (position.x, position.y)
+                );
+                Some(position)
+            } else {
+                None
+            }
+        }))
+        .collect::<Vec<_>>();
+
+    let windows = path.iter().zip(path.iter().skip(1)).collect::<Vec<_>>();
+
+    // Shoelace formula
+    let shoelace = windows
+        .iter()
+        .map(|(a, b)| a.x * b.y - a.y * b.x)
+        .sum::<i64>() as u64
+        / 2;
+
+    // Pick's theorem
+    let pick = 1 + commands.iter().map(|command| command.count).sum::<u64>() / 2;
+
+    trace!("area", shoelace = shoelace, pick = pick);
+
+    shoelace + pick
+}
+
+trait ParseCommandsStrategy {
+    fn parse_command(line: Line<'_>) -> Result<Command, ParseError>;
+}
+
+struct Part1Strategy;
+
+impl ParseCommandsStrategy for Part1Strategy {
+    fn parse_command(line: Line<'_>) -> Result<Command, ParseError> {
+        let (movement, _) = line.split_once(line.text, " (")?;
+        let (direction, count) = line.split_once(movement, " ")?;
+
+        Ok(Command {
+            direction: line.parse_char(direction, "a direction, one of `UDLR`", parse_direction)?,
+            count: line.parse(count, "a distance")?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Command {
+    direction: Direction,
+    count: u64,
+}
+
+fn parse_direction(c: char) -> Option<Direction> {
+    match c {
+        'U' => Some(Direction::Up),
+        'D' => Some(Direction::Down),
+        'L' => Some(Direction::Left),
+        'R' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+struct Part2Strategy;
+
+impl ParseCommandsStrategy for Part2Strategy {
+    fn parse_command(line: Line<'_>) -> Result<Command, ParseError> {
+        let (_, command) = line.split_once(line.text, " (#")?;
+        let command = command
+            .strip_suffix(')')
+            .ok_or_else(|| line.error_at_end("expected `)`"))?;
+
+        if command.len() != 6 || !command.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(line.error(command, "expected a colour of six hex digits"));
+        }
+
+        let count = u64::from_str_radix(&command[0..5], 16)
+            .map_err(|_| line.error(&command[0..5], "expected a hex distance"))?;
+        let direction =
+            line.parse_char(&command[5..], "a direction, one of `0123`", |c| match c {
+                '0' => Some(Direction::Right),
+                '1' => Some(Direction::Down),
+                '2' => Some(Direction::Left),
+                '3' => Some(Direction::Up),
+                _ => None,
+            })?;
+
+        Ok(Command { direction, count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day18::solve_part1(INPUT_TEST).unwrap(), 62);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day18::solve_part2(INPUT_TEST).unwrap(), 952_408_144_115_u64);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day18>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day18>(30);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day18>(0..20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day18>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
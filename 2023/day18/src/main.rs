@@ -0,0 +1,5 @@
+use advent_of_code_2023_day18::Day18;
+
+fn main() {
+    aoc_common::solution::main::<Day18>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day18;
+
+// Digs out the trench on a grid and floods the ground around it, rather than using the
+// shoelace formula and Pick's theorem. The grid is squashed down to just the rows and
+// columns where the trench turns, with each cell standing for however many metres
+// there are until the next, so that part 2's distances fit
+impl Reference for Day18 {
+    const REFERENCE_SIZE: usize = 10;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        let corners = input
+            .lines()
+            .scan((0i64, 0i64), |(x, y), line| {
+                let mut words = line.split_whitespace();
+                let (direction, count) = (words.next().unwrap(), words.next().unwrap());
+                let colour = words.next().unwrap();
+
+                let (direction, count) = match part {
+                    Part::One => (direction.chars().next().unwrap(), count.parse().unwrap()),
+                    Part::Two => (
+                        ['R', 'D', 'L', 'U'][usize::from(colour.as_bytes()[7] - b'0')],
+                        i64::from_str_radix(&colour[2..7], 16).unwrap(),
+                    ),
+                };
+
+                match direction {
+                    'R' => *x += count,
+                    'L' => *x -= count,
+                    'D' => *y += count,
+                    _ => *y -= count,
+                }
+
+                Some((*x, *y))
+            })
+            .collect::<Vec<_>>();
+
+        // Each corner's own row and column is a cell of its own, with another cell
+        // starting right after it, and a border of ground all the way round
+        let squash = |axis: fn(&(i64, i64)) -> i64| {
+            let mut lines = corners
+                .iter()
+                .flat_map(|corner| [axis(corner), axis(corner) + 1])
+                .collect::<Vec<_>>();
+
+            lines.push(lines.iter().min().unwrap() - 1);
+            lines.push(lines.iter().max().unwrap() + 1);
+            lines.sort_unstable();
+            lines.dedup();
+            lines
+        };
+
+        let columns = squash(|(x, _)| *x);
+        let rows = squash(|(_, y)| *y);
+        let column = |x| columns.binary_search(&x).unwrap();
+        let row = |y| rows.binary_search(&y).unwrap();
+
+        let mut trench = HashSet::new();
+
+        for (i, &(x1, y1)) in corners.iter().enumerate() {
+            let (x2, y2) = corners[(i + 1) % corners.len()];
+
+            for cell_x in column(x1.min(x2))..=column(x1.max(x2)) {
+                for cell_y in row(y1.min(y2))..=row(y1.max(y2)) {
+                    trench.insert((cell_x, cell_y));
+                }
+            }
+        }
+
+        let (width, height) = (columns.len() - 1, rows.len() - 1);
+        let mut outside = HashSet::from([(0, 0)]);
+        let mut stack = vec![(0usize, 0usize)];
+
+        while let Some((x, y)) = stack.pop() {
+            let neighbours = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+
+            for (x, y) in neighbours {
+                if x < width && y < height && !trench.contains(&(x, y)) && outside.insert((x, y)) {
+                    stack.push((x, y));
+                }
+            }
+        }
+
+        let area = |(x, y): (usize, usize)| (columns[x + 1] - columns[x]) * (rows[y + 1] - rows[y]);
+
+        let total = (columns[width] - columns[0]) * (rows[height] - rows[0]);
+
+        (total - outside.into_iter().map(area).sum::<i64>()).into()
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::VecDeque;
+
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::{Day19, FIRST_WORKFLOW, PROPERTIES};
+
+const PARTS: usize = 200;
+
+// `size` is the number of workflows, at least 1. They form a tree from `in`, so that
+// sorting a part never goes round in circles
+impl Generate for Day19 {
+    const DEFAULT_SIZE: usize = 550;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut names = generate::names(rng, size + 1, 3)
+            .into_iter()
+            .filter(|name| name != FIRST_WORKFLOW)
+            .take(size - 1)
+            .collect::<Vec<_>>();
+
+        let mut queue = VecDeque::from([FIRST_WORKFLOW.to_string()]);
+        let mut workflows = Vec::new();
+
+        while let Some(name) = queue.pop_front() {
+            let mut target = |rng: &mut StdRng| match names.pop() {
+                Some(next) if rng.gen_ratio(2, 3) => {
+                    queue.push_back(next.clone());
+                    next
+                }
+                // Not used after all
+                Some(next) => {
+                    names.push(next);
+                    if rng.gen() { "A" } else { "R" }.to_string()
+                }
+                None => if rng.gen() { "A" } else { "R" }.to_string(),
+            };
+
+            let mut rules = (0..rng.gen_range(1..=3))
+                .map(|_| {
+                    format!(
+                        "{}{}{}:{}",
+                        PROPERTIES.choose(rng).unwrap(),
+                        if rng.gen() { '<' } else { '>' },
+                        rng.gen_range(1..4000),
+                        target(rng)
+                    )
+                })
+                .collect::<Vec<_>>();
+            rules.push(target(rng));
+
+            workflows.push(format!("{name}{{{}}}", rules.join(",")));
+        }
+
+        workflows.shuffle(rng);
+
+        let parts = (0..PARTS).map(|_| {
+            let ratings = PROPERTIES
+                .iter()
+                .map(|property| format!("{property}={}", rng.gen_range(1..=4000)))
+                .collect::<Vec<_>>();
+
+            format!("{{{}}}", ratings.join(","))
+        });
+
+        let parts = parts.collect::<Vec<_>>();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+    }
+}
@@ -0,0 +1,461 @@
+mod generate;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    interval::{IntervalBox, IntervalSet},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed<'input> = System<'input>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(system: &Self::Parsed<'_>) -> Answer {
+        system
+            .machine_parts
+            .iter()
+            .enumerate()
+            .filter(|(i, machine_part)| {
+                let mut current_workflow = FIRST_WORKFLOW;
+
+                let final_decision = loop {
+                    let workflow = system.workflows.get(current_workflow).unwrap();
+                    let result = workflow.apply(machine_part);
+
+                    trace!(
+                        "workflow",
+                        part = i + 1,
+                        workflow = current_workflow,
+                        next = result.target()
+                    );
+
+                    match result {
+                        WorkflowStepResult::HasSubsequent(key) => {
+                            current_workflow = key;
+                        }
+                        WorkflowStepResult::WorkflowFinished(final_decision) => {
+                            break final_decision;
+                        }
+                    }
+                };
+
+                match final_decision {
+                    FinalWorkflowDecision::Accept => true,
+                    FinalWorkflowDecision::Reject => false,
+                }
+            })
+            .map(|(_, machine_part)| machine_part.get_rating())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(system: &Self::Parsed<'_>) -> Answer {
+        // Every rating from 1 to 4000 for each property
+        let all_parts = IntervalBox::new(PROPERTIES.map(|_| IntervalSet::from(1..4001)));
+
+        let mut unprocessed = vec![(FIRST_WORKFLOW, all_parts)];
+
+        let mut accepted = Vec::new();
+
+        while let Some((workflow, parts)) = unprocessed.pop() {
+            let workflow = system.workflows.get(workflow).unwrap();
+
+            for (parts, workflow_step_result) in workflow.filter_applicable(parts) {
+                trace!(
+                    "workflow",
+                    workflow = workflow.key,
+                    parts = parts.volume(),
+                    next = workflow_step_result.target()
+                );
+
+                match workflow_step_result {
+                    WorkflowStepResult::HasSubsequent(key) => {
+                        unprocessed.push((key, parts));
+                    }
+                    WorkflowStepResult::WorkflowFinished(FinalWorkflowDecision::Accept) => {
+                        accepted.push(parts)
+                    }
+                    WorkflowStepResult::WorkflowFinished(FinalWorkflowDecision::Reject) => {}
+                }
+            }
+        }
+
+        accepted
+            .iter()
+            // At most 4000^4 each, which fits
+            .map(|parts| parts.volume() as u64)
+            .sum::<u64>()
+            .into()
+    }
+}
+
+const PROPERTIES: [&str; 4] = ["x", "m", "a", "s"];
+const FIRST_WORKFLOW: &str = "in";
+
+fn parse_input(input: &str) -> Result<System<'_>, ParseError> {
+    let mut blocks = parse::blocks(input);
+
+    let workflow_lines = blocks
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a list of workflows"))??
+        .into_iter()
+        .map(|line| Ok((line, Workflow::try_from(line)?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let mut keys = HashSet::new();
+    for (line, workflow) in &workflow_lines {
+        if !keys.insert(workflow.key) {
+            return Err(line.error(workflow.key, "workflow is defined more than once"));
+        }
+    }
+
+    if !keys.contains(FIRST_WORKFLOW) {
+        return Err(ParseError::end_of_input(
+            input,
+            format!("expected a workflow named `{FIRST_WORKFLOW}`"),
+        ));
+    }
+
+    // Every workflow sent to has to exist, otherwise sorting would get stuck
+    for (line, workflow) in &workflow_lines {
+        for rule in &workflow.rules {
+            let (Rule::Unconditional(WorkflowStepResult::HasSubsequent(target))
+            | Rule::Conditional(_, WorkflowStepResult::HasSubsequent(target))) = rule
+            else {
+                continue;
+            };
+
+            if !keys.contains(target) {
+                return Err(line.error(target, "no workflow has this name"));
+            }
+        }
+    }
+
+    let workflows = workflow_lines
+        .into_iter()
+        .map(|(_, workflow)| (workflow.key, workflow))
+        .collect();
+
+    let machine_parts = match blocks.next() {
+        Some(block) => block?
+            .into_iter()
+            .map(MachinePart::try_from)
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+
+    if let Some(block) = blocks.next() {
+        let line = block?[0];
+        return Err(line.error(line.text, "expected only the workflows and the parts"));
+    }
+
+    Ok(System {
+        workflows,
+        machine_parts,
+    })
+}
+
+fn parse_property<'a>(line: &Line<'a>, property: &'a str) -> Result<&'a str, ParseError> {
+    if PROPERTIES.contains(&property) {
+        Ok(property)
+    } else {
+        Err(line.error(property, "expected a property, one of `xmas`"))
+    }
+}
+
+pub struct System<'a> {
+    workflows: HashMap<&'a str, Workflow<'a>>,
+    machine_parts: Vec<MachinePart<'a>>,
+}
+
+struct Workflow<'a> {
+    key: &'a str,
+    rules: Vec<Rule<'a>>,
+}
+
+impl<'a> Workflow<'a> {
+    fn apply(&self, machine_part: &MachinePart) -> &WorkflowStepResult<'a> {
+        for rule in &self.rules {
+            if let Some(result) = rule.apply(machine_part) {
+                return result;
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+impl<'a> TryFrom<Line<'a>> for Workflow<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'a>) -> Result<Self, Self::Error> {
+        let (key, rules) = line.split_once(line.text, "{")?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| line.error_at_end("expected `}`"))?;
+
+        let rule_texts = rules.split(',').collect::<Vec<_>>();
+        let rules = rule_texts
+            .iter()
+            .map(|rule| Rule::parse(&line, rule))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Every part has to end up somewhere
+        if let Some(Rule::Conditional(..)) = rules.last() {
+            return Err(line.error(
+                rule_texts[rule_texts.len() - 1],
+                "expected the last rule to have no condition",
+            ));
+        }
+
+        Ok(Self { key, rules })
+    }
+}
+
+enum Rule<'a> {
+    Unconditional(WorkflowStepResult<'a>),
+    Conditional(Condition<'a>, WorkflowStepResult<'a>),
+}
+
+impl<'a> Rule<'a> {
+    fn apply(&self, machine_part: &MachinePart) -> Option<&WorkflowStepResult<'a>> {
+        match self {
+            Rule::Unconditional(target) => Some(target),
+            Rule::Conditional(condition, target) => {
+                if condition.compare(machine_part) {
+                    Some(target)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+enum Condition<'a> {
+    Property(&'a str, Comparison, usize),
+}
+
+impl<'a> Condition<'a> {
+    fn parse(line: &Line<'a>, input: &'a str) -> Result<Self, ParseError> {
+        let index = input
+            .find(['<', '>'])
+            .ok_or_else(|| line.error(input, "expected a condition using `<` or `>`"))?;
+        let (property, rest) = input.split_at(index);
+        let (comparison, compared_value) = rest.split_at(1);
+
+        let comparison = match comparison {
+            "<" => Comparison::LessThan,
+            _ => Comparison::GreaterThan,
+        };
+
+        Ok(Self::Property(
+            parse_property(line, property)?,
+            comparison,
+            line.parse(compared_value, "a rating")?,
+        ))
+    }
+}
+
+impl<'a> Condition<'a> {
+    fn compare(&self, machine_part: &MachinePart) -> bool {
+        match self {
+            Self::Property(property, comparison, value) => match comparison {
+                Comparison::LessThan => machine_part.properties[property] < *value,
+                Comparison::GreaterThan => machine_part.properties[property] > *value,
+            },
+        }
+    }
+}
+
+enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+enum WorkflowStepResult<'a> {
+    HasSubsequent(&'a str),
+    WorkflowFinished(FinalWorkflowDecision),
+}
+
+impl WorkflowStepResult<'_> {
+    // As written in the workflow
+    fn target(&self) -> &str {
+        match self {
+            Self::HasSubsequent(key) => key,
+            Self::WorkflowFinished(FinalWorkflowDecision::Accept) => "A",
+            Self::WorkflowFinished(FinalWorkflowDecision::Reject) => "R",
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowStepResult<'a> {
+    fn from(input: &'a str) -> Self {
+        match input {
+            "A" => Self::WorkflowFinished(FinalWorkflowDecision::Accept),
+            "R" => Self::WorkflowFinished(FinalWorkflowDecision::Reject),
+            _ => Self::HasSubsequent(input),
+        }
+    }
+}
+
+enum FinalWorkflowDecision {
+    Accept,
+    Reject,
+}
+
+impl<'a> Rule<'a> {
+    fn parse(line: &Line<'a>, input: &'a str) -> Result<Self, ParseError> {
+        if let Some((condition, target)) = input.split_once(':') {
+            Ok(Self::Conditional(
+                Condition::parse(line, condition)?,
+                target.into(),
+            ))
+        } else {
+            Ok(Self::Unconditional(input.into()))
+        }
+    }
+}
+
+struct MachinePart<'a> {
+    properties: HashMap<&'a str, usize>,
+}
+
+impl<'a> TryFrom<Line<'a>> for MachinePart<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'a>) -> Result<Self, Self::Error> {
+        let input = line
+            .text
+            .strip_prefix('{')
+            .and_then(|input| input.strip_suffix('}'))
+            .ok_or_else(|| line.error(line.text, "expected a part like `{x=1,m=2,a=3,s=4}`"))?;
+
+        let properties = input
+            .split(',')
+            .map(|part| {
+                let (key, value) = line.split_once(part, "=")?;
+
+                Ok((parse_property(&line, key)?, line.parse(value, "a rating")?))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        if properties.len() != PROPERTIES.len() {
+            return Err(line.error(line.text, "expected a rating for each of `xmas`"));
+        }
+
+        Ok(Self { properties })
+    }
+}
+
+impl<'a> MachinePart<'a> {
+    fn get_rating(&self) -> usize {
+        self.properties.values().sum()
+    }
+}
+
+impl<'a> Workflow<'a> {
+    // Splits up the parts by the rule they're sent on by, skipping rules none reach
+    fn filter_applicable(&self, parts: Parts) -> Vec<(Parts, &WorkflowStepResult<'a>)> {
+        let mut results = Vec::new();
+
+        let mut remaining = parts;
+
+        for rule in &self.rules {
+            let ((applicable, target), not_applicable) = rule.filter_applicable(remaining);
+
+            if !applicable.is_empty() {
+                results.push((applicable, target));
+            }
+
+            match not_applicable {
+                Some(not_applicable) if !not_applicable.is_empty() => remaining = not_applicable,
+                _ => break,
+            }
+        }
+
+        results
+    }
+}
+
+impl<'a> Rule<'a> {
+    fn filter_applicable(&self, parts: Parts) -> ((Parts, &WorkflowStepResult<'a>), Option<Parts>) {
+        match self {
+            Rule::Unconditional(target) => ((parts, target), None),
+            Rule::Conditional(condition, target) => {
+                let (applicable, not_applicable) = condition.filter_applicable(&parts);
+
+                ((applicable, target), Some(not_applicable))
+            }
+        }
+    }
+}
+
+impl Condition<'_> {
+    // Returns the parts that meet the condition, then those that don't
+    fn filter_applicable(&self, parts: &Parts) -> (Parts, Parts) {
+        match self {
+            Self::Property(property, comparison, value) => {
+                let axis = PROPERTIES.iter().position(|p| p == property).unwrap();
+                let value = *value as i64;
+
+                match comparison {
+                    Comparison::LessThan => parts.split_at(axis, value),
+                    Comparison::GreaterThan => {
+                        let (not_applicable, applicable) = parts.split_at(axis, value + 1);
+                        (applicable, not_applicable)
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The ratings of a whole range of parts, with an axis for each of `PROPERTIES`
+type Parts = IntervalBox<4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day19::solve_part1(INPUT_TEST).unwrap(), 19_114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day19::solve_part2(INPUT_TEST).unwrap(),
+            167_409_079_868_000_u64
+        );
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day19>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day19>(20);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
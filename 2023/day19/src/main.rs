@@ -0,0 +1,5 @@
+use advent_of_code_2023_day19::Day19;
+
+fn main() {
+    aoc_common::solution::main::<Day19>(env!("CARGO_MANIFEST_DIR"));
+}
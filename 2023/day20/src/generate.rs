@@ -0,0 +1,68 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day20;
+
+const COUNTERS: usize = 4;
+
+// `size` is the number of flip-flops in each counter, from 2 up to 16 so that the
+// product of the periods fits in a u64. As in the real input,
+// the broadcaster starts several counters, each a chain of flip-flops counting up in
+// binary, with a conjunction that notices when the count reaches its period and resets
+// it. Each sends a high pulse to `jz` through an inverter once a period, and `jz`
+// drives `rx`
+impl Generate for Day20 {
+    const DEFAULT_SIZE: usize = 12;
+    const MIN_SIZE: usize = 2;
+    const MAX_SIZE: usize = 16;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut names = generate::names(rng, COUNTERS * (size + 2) + 2, 2)
+            .into_iter()
+            .filter(|name| name != "jz" && name != "rx");
+
+        let mut modules = Vec::new();
+        let mut starts = Vec::new();
+
+        for _ in 0..COUNTERS {
+            let flip_flops = names.by_ref().take(size).collect::<Vec<_>>();
+            let hub = names.next().unwrap();
+            let inverter = names.next().unwrap();
+
+            // Odd, and using every flip-flop
+            let period = rng.gen_range(1 << (size - 1)..1 << size) | 1;
+
+            let mut hub_outputs = vec![flip_flops[0].clone()];
+
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut outputs = flip_flops
+                    .get(bit + 1)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if period & (1 << bit) != 0 {
+                    outputs.push(hub.clone());
+                } else {
+                    hub_outputs.push(flip_flop.clone());
+                }
+
+                modules.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+            }
+
+            hub_outputs.push(inverter.clone());
+            hub_outputs.shuffle(rng);
+
+            modules.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+            modules.push(format!("&{inverter} -> jz"));
+
+            starts.push(flip_flops[0].clone());
+        }
+
+        modules.push(format!("broadcaster -> {}", starts.join(", ")));
+        modules.push("&jz -> rx".to_string());
+        modules.shuffle(rng);
+
+        modules.join("\n") + "\n"
+    }
+}
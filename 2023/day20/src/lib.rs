@@ -0,0 +1,405 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{
+    cycle::{self, Schedule},
+    graph,
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Parsed<'input> = CommunicationNetwork<'input>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        CommunicationNetwork::try_from(input)
+    }
+
+    fn part1(network: &Self::Parsed<'_>) -> Answer {
+        let mut network = network.clone();
+
+        let (num_high, num_low): (usize, usize) = (1..=1000)
+            .map(|press| {
+                let pulses = network.broadcast(Pulse::Low);
+
+                for (from, pulse, to) in &pulses {
+                    trace!("pulse", press = press, from = from, pulse = pulse, to = to);
+                }
+
+                pulses
+                    .iter()
+                    .fold((0, 0), |aggregate, (_, pulse, _)| match pulse {
+                        Pulse::High => (aggregate.0 + 1, aggregate.1),
+                        Pulse::Low => (aggregate.0, aggregate.1 + 1),
+                    })
+            })
+            .fold((0, 0), |aggregate, result| {
+                (aggregate.0 + result.0, aggregate.1 + result.1)
+            });
+
+        (num_high * num_low).into()
+    }
+
+    fn part2(network: &Self::Parsed<'_>) -> Answer {
+        // The examples have no `rx`, so no answer for part 2
+        let Some(feeder) = network.rx_feeder else {
+            return Answer::Unavailable;
+        };
+
+        // The feeder is the only input to rx, and is a conjunction, so rx gets a low
+        // pulse once all of the feeder's inputs send it high on the same press. Each of
+        // those inputs is driven by its own part of the network, which goes round in a
+        // loop: find when each sends high within its loop, then solve for when they all
+        // coincide
+        let schedules = network.modules[feeder]
+            .inputs
+            .iter()
+            .map(|sender| {
+                let upstream = network.upstream_of(sender);
+
+                let mut network = network.clone();
+                let mut presses = 0;
+                let mut high_presses = Vec::new();
+
+                let looping = cycle::find_loop_by_key(
+                    &mut network,
+                    |network| {
+                        let sends_high =
+                            network
+                                .broadcast(Pulse::Low)
+                                .contains(&(*sender, Pulse::High, feeder));
+
+                        if sends_high {
+                            high_presses.push(presses);
+                        }
+
+                        presses += 1;
+                    },
+                    |network| network.state_of(&upstream),
+                );
+
+                trace!(
+                    "loop",
+                    input = sender,
+                    prefix = looping.prefix,
+                    period = looping.period,
+                    high_presses = high_presses
+                );
+
+                Schedule {
+                    looping,
+                    steps: high_presses,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Steps count from zero, but presses from one
+        cycle::first_common_step(&schedules).map_or(Answer::Unavailable, |step| (step + 1).into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommunicationNetwork<'a> {
+    modules: HashMap<&'a str, ConnectedModule<'a>>,
+    // The conjunction that is the only input to `rx`, if there is an `rx`
+    rx_feeder: Option<&'a str>,
+}
+
+impl<'a> CommunicationNetwork<'a> {
+    fn broadcast(&mut self, pulse: Pulse) -> Vec<(&str, Pulse, &str)> {
+        let mut stack = VecDeque::from([("button", pulse, Module::<'_>::KEY_BROADCASTER)]);
+
+        let mut sent_signals = Vec::new();
+
+        while let Some((source_key, pulse, target_key)) = stack.pop_front() {
+            sent_signals.push((source_key, pulse, target_key));
+
+            let connected_module = self.modules.get_mut(target_key).unwrap();
+
+            if let Some(output) = connected_module
+                .module
+                .get_output_for_input(source_key, pulse)
+            {
+                for output_key in connected_module.outputs.iter() {
+                    stack.push_back((target_key, output, *output_key));
+                }
+            }
+        }
+
+        sent_signals
+    }
+
+    // Every module whose pulses can reach `key`, including itself, in a stable order
+    fn upstream_of(&self, key: &'a str) -> Vec<&'a str> {
+        let inputs = |key: &&'a str| self.modules[key].inputs.clone();
+
+        let mut upstream = graph::bfs_distances([key], &inputs, None)
+            .into_keys()
+            .collect::<Vec<_>>();
+        upstream.sort_unstable();
+        upstream
+    }
+
+    // Enough to tell whether the given modules are in the same state as before
+    fn state_of(&self, keys: &[&str]) -> Vec<bool> {
+        let mut state = Vec::new();
+
+        for key in keys {
+            match &self.modules[key].module {
+                Module::FlipFlop(flip_flop) => state.push(matches!(flip_flop, FlipFlopState::On)),
+                Module::Conjunction(inputs) => {
+                    let mut inputs = inputs.iter().collect::<Vec<_>>();
+                    inputs.sort_unstable_by_key(|(input, _)| **input);
+
+                    state.extend(inputs.iter().map(|(_, pulse)| **pulse == Pulse::High));
+                }
+                Module::OutputSink | Module::Broadcaster => {}
+            }
+        }
+
+        state
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CommunicationNetwork<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let mut modules: HashMap<&'a str, ConnectedModule<'a>> = parse::lines(value)
+            .map(ConnectedModule::try_from)
+            .map(|connected_module| {
+                connected_module.map(|connected_module| (connected_module.key, connected_module))
+            })
+            .collect::<Result<_, _>>()?;
+
+        if !modules.contains_key(Module::KEY_BROADCASTER) {
+            return Err(ParseError::end_of_input(
+                value,
+                format!("expected a `{}` module", Module::KEY_BROADCASTER),
+            ));
+        }
+
+        let mut inputs_by_module_key = modules.values().fold(
+            HashMap::<&str, Vec<&str>>::new(),
+            |mut connections, connected_module| {
+                for output in connected_module.outputs.iter() {
+                    connections
+                        .entry(output)
+                        .or_default()
+                        .push(connected_module.key);
+                }
+
+                connections
+            },
+        );
+
+        for (key, inputs) in inputs_by_module_key.drain() {
+            let connected_module = modules
+                .entry(key)
+                .or_insert_with_key(|key| ConnectedModule {
+                    key,
+                    ..Default::default()
+                });
+
+            connected_module.module.assign_inputs(&inputs);
+            connected_module.inputs = inputs;
+        }
+
+        let rx_feeder = match modules.get(Module::KEY_RX).map(|rx| rx.inputs.as_slice()) {
+            None => None,
+            Some(&[feeder]) if matches!(modules[feeder].module, Module::Conjunction(_)) => {
+                Some(feeder)
+            }
+            Some(_) => {
+                return Err(ParseError::end_of_input(
+                    value,
+                    format!(
+                        "expected `{}` to have a single conjunction as its input",
+                        Module::KEY_RX
+                    ),
+                ))
+            }
+        };
+
+        Ok(Self { modules, rx_feeder })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ConnectedModule<'a> {
+    key: &'a str,
+    module: Module<'a>,
+    inputs: Vec<&'a str>,
+    outputs: Vec<&'a str>,
+}
+
+impl<'a> TryFrom<Line<'a>> for ConnectedModule<'a> {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'a>) -> Result<Self, Self::Error> {
+        let (module, outputs) = line.split_once(line.text, " -> ")?;
+
+        let (key, module) = Module::parse_definition(module).ok_or_else(|| {
+            line.error(
+                module,
+                "expected `broadcaster`, or a name prefixed by `%` or `&`",
+            )
+        })?;
+        let outputs = outputs.trim().split(", ").collect();
+
+        Ok(Self {
+            key,
+            module,
+            outputs,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+enum Module<'a> {
+    #[default]
+    OutputSink,
+    Broadcaster,
+    FlipFlop(FlipFlopState),
+    Conjunction(HashMap<&'a str, Pulse>),
+}
+
+#[derive(Debug, Clone, Default)]
+enum FlipFlopState {
+    #[default]
+    Off,
+    On,
+}
+
+impl FlipFlopState {
+    fn opposite(&self) -> Self {
+        match self {
+            Self::Off => Self::On,
+            Self::On => Self::Off,
+        }
+    }
+}
+
+impl<'a> Module<'a> {
+    const KEY_BROADCASTER: &'static str = "broadcaster";
+    const KEY_RX: &'static str = "rx";
+
+    fn parse_definition(value: &'_ str) -> Option<(&'_ str, Self)> {
+        if value == Self::KEY_BROADCASTER {
+            Some((Self::KEY_BROADCASTER, Module::Broadcaster))
+        } else if let Some(label) = value.strip_prefix('%') {
+            Some((label, Module::FlipFlop(FlipFlopState::default())))
+        } else {
+            let label = value.strip_prefix('&')?;
+            Some((label, Module::Conjunction(HashMap::new())))
+        }
+    }
+
+    fn assign_inputs(&mut self, inputs: &[&'a str]) {
+        // Only conjunctions directly care about their inputs
+        if let Self::Conjunction(module_inputs) = self {
+            for input in inputs.to_owned().drain(..) {
+                module_inputs.insert(input, Pulse::Low);
+            }
+        }
+    }
+
+    fn get_output_for_input(&mut self, source: &'_ str, input: Pulse) -> Option<Pulse> {
+        match self {
+            Self::OutputSink => None,
+            Self::Broadcaster => Some(input),
+            Self::FlipFlop(ref mut state) => match input {
+                Pulse::High => None,
+                Pulse::Low => {
+                    *state = state.opposite();
+
+                    match state {
+                        FlipFlopState::On => Some(Pulse::High),
+                        FlipFlopState::Off => Some(Pulse::Low),
+                    }
+                }
+            },
+            Self::Conjunction(ref mut module_inputs) => {
+                *module_inputs.get_mut(source).unwrap() = input;
+
+                if module_inputs.values().all(|&input| input == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pulse {
+    High,
+    Low,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST_1: &str = include_str!("../input_test_1.txt");
+    const INPUT_TEST_2: &str = include_str!("../input_test_2.txt");
+
+    #[test]
+    fn part1_example_1() {
+        assert_eq!(Day20::solve_part1(INPUT_TEST_1).unwrap(), 32_000_000);
+    }
+
+    #[test]
+    fn part1_example_2() {
+        assert_eq!(Day20::solve_part1(INPUT_TEST_2).unwrap(), 11_687_500);
+    }
+
+    #[test]
+    fn examples_have_no_part2() {
+        assert_eq!(
+            Day20::solve_part2(INPUT_TEST_2).unwrap(),
+            Answer::Unavailable
+        );
+    }
+
+    #[test]
+    fn reports_missing_modules() {
+        let error = Day20::parse("%a -> b\n&b -> rx\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+
+        let error = Day20::parse("broadcaster -> a, b\n%a -> rx\n%b -> rx\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 9));
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day20>(INPUT_TEST_1);
+        aoc_common::generate::assert_truncated_inputs_solve::<Day20>(INPUT_TEST_2);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day20>(6);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day20>(0..10);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day20>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day20::Day20;
+
+fn main() {
+    aoc_common::solution::main::<Day20>(env!("CARGO_MANIFEST_DIR"));
+}
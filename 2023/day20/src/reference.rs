@@ -0,0 +1,81 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day20;
+
+// Presses the button until `rx` gets a low pulse, rather than working out when each of
+// `jz`'s inputs goes high and when those line up
+impl Reference for Day20 {
+    // Counters of 4 flip-flops line up within 15 * 13 * 11 * 9 presses
+    const REFERENCE_SIZE: usize = 4;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        let modules = input
+            .lines()
+            .map(|line| {
+                let (module, outputs) = line.split_once(" -> ").unwrap();
+                let name = module.trim_start_matches(['%', '&']);
+
+                (name, (module.as_bytes()[0], outputs.split(", ").collect()))
+            })
+            .collect::<HashMap<&str, (u8, Vec<&str>)>>();
+
+        let mut on = HashMap::new();
+        let mut remembered = HashMap::<&str, HashMap<&str, bool>>::new();
+
+        for (name, (_, outputs)) in &modules {
+            for output in outputs {
+                remembered.entry(output).or_default().insert(name, false);
+            }
+        }
+
+        let (mut low, mut high) = (0u64, 0u64);
+
+        for presses in 1u64.. {
+            let mut pulses = VecDeque::from([("button", false, "broadcaster")]);
+
+            while let Some((from, pulse, to)) = pulses.pop_front() {
+                if pulse {
+                    high += 1;
+                } else {
+                    low += 1;
+                }
+
+                if to == "rx" && !pulse && part == Part::Two {
+                    return presses.into();
+                }
+
+                let Some((kind, outputs)) = modules.get(to) else {
+                    continue;
+                };
+
+                let sent = match kind {
+                    b'%' if pulse => continue,
+                    b'%' => {
+                        let on = on.entry(to).or_insert(false);
+                        *on = !*on;
+                        *on
+                    }
+                    b'&' => {
+                        let remembered = remembered.get_mut(to).unwrap();
+                        remembered.insert(from, pulse);
+                        !remembered.values().all(|pulse| *pulse)
+                    }
+                    _ => pulse,
+                };
+
+                pulses.extend(outputs.iter().map(|output| (to, sent, *output)));
+            }
+
+            if presses == 1000 && part == Part::One {
+                return (low * high).into();
+            }
+        }
+
+        unreachable!()
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day21;
+
+// `size` is the side of the garden, which must be odd. Part 2 only works out for sides
+// that divide evenly into the steps taken after leaving the middle: 131, as in the real
+// input, or 3 or 393; other sides are for checking the extrapolation at other numbers of
+// steps. As in the real input, the start is in the middle, its row and column and the edges are clear, and
+// so is a diamond halfway out, and the rocks are scattered so that none wall anything
+// off. That's what keeps the number of plots reached growing quadratically
+impl Generate for Day21 {
+    const DEFAULT_SIZE: usize = 131;
+
+    fn check_size(size: usize) -> Result<(), String> {
+        if size.is_multiple_of(2) {
+            return Err("the garden must have a middle plot to start from, so an odd size".into());
+        }
+
+        Ok(())
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let middle = size / 2;
+
+        let clear = |x: usize, y: usize| {
+            let distance = x.abs_diff(middle) + y.abs_diff(middle);
+
+            x == middle
+                || y == middle
+                || x == 0
+                || y == 0
+                || x == size - 1
+                || y == size - 1
+                || distance.abs_diff(middle) <= 1
+        };
+
+        // Rocks are only placed where no earlier one is next to them, even diagonally
+        let mut rocks = vec![vec![false; size]; size];
+
+        for y in 0..size {
+            for x in 0..size {
+                let crowded = (y.saturating_sub(1)..=y)
+                    .flat_map(|ny| {
+                        (x.saturating_sub(1)..=(x + 1).min(size - 1)).map(move |nx| (nx, ny))
+                    })
+                    .any(|(nx, ny)| rocks[ny][nx]);
+
+                rocks[y][x] = !clear(x, y) && !crowded && rng.gen_ratio(1, 5);
+            }
+        }
+
+        generate::grid(rng, size, size, |_, x, y| {
+            if (x, y) == (middle, middle) {
+                'S'
+            } else if rocks[y][x] {
+                '#'
+            } else {
+                '.'
+            }
+        })
+    }
+}
@@ -0,0 +1,226 @@
+mod generate;
+
+use aoc_common::{
+    geometry::Position,
+    graph,
+    grid::{Grid, GridView},
+    parse::ParseError,
+    polynomial::Polynomial,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Parsed<'input> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_garden(input)
+    }
+
+    fn part1(garden: &Self::Parsed<'_>) -> Answer {
+        solve_part1(garden, 64).into()
+    }
+
+    fn part2(garden: &Self::Parsed<'_>) -> Answer {
+        solve_part2(garden, PART2_STEPS).map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+const PART2_STEPS: usize = 26_501_365;
+
+fn solve_part1(garden: &Garden, target_steps: usize) -> usize {
+    count_possible_ending_tiles(&garden.grid, target_steps, &[&garden.start])
+}
+
+pub struct Garden {
+    grid: Grid<Tile>,
+    start: Position,
+}
+
+fn parse_garden(input: &str) -> Result<Garden, ParseError> {
+    let tiles = Grid::try_parse(input, "`.`, `#` or `S`", |c| {
+        matches!(c, '.' | '#' | 'S').then_some(c)
+    })?;
+    let start = tiles
+        .find(|c| *c == 'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a start tile `S`"))?;
+
+    let grid = tiles.map(|c| match c {
+        '#' => Tile::Rock,
+        _ => Tile::GardenPlot,
+    });
+
+    Ok(Garden { grid, start })
+}
+
+fn count_possible_ending_tiles(
+    grid: &impl GridView<Tile>,
+    target_steps: usize,
+    start: &[&Position],
+) -> usize {
+    let neighbours = |position: &Position| {
+        position
+            .neighbours()
+            .filter(|next| grid.get(next).is_some_and(|tile| *tile != Tile::Rock))
+    };
+
+    let distances = graph::bfs_distances(
+        start.iter().map(|start| **start),
+        &neighbours,
+        Some(target_steps),
+    );
+
+    trace!(
+        "spread",
+        steps = target_steps,
+        // How many plots are first reached on each step
+        new_plots = distances.values().fold(
+            vec![0; distances.values().max().map_or(0, |max| max + 1)],
+            |mut new, steps| {
+                new[*steps] += 1;
+                new
+            }
+        )
+    );
+
+    // Any tile reached in fewer steps can be returned to by stepping back and forth,
+    // so long as there's an even number of steps left over to do so
+    distances
+        .values()
+        .filter(|steps| *steps % 2 == target_steps % 2)
+        .count()
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Tile {
+    GardenPlot,
+    Rock,
+}
+
+// `None` for gardens that aren't shaped like the real input
+fn solve_part2(garden: &Garden, target_steps: usize) -> Option<usize> {
+    // So it turns out that despite noticing a bunch of stuff with the grid,
+    // it's actually possible to extrapolate the answer by calculating the
+    // first few groups' worth of values with the slower algorithm,
+    // then it's go-go-day09!
+
+    let Garden { grid, start } = garden;
+    let infinite_grid = grid.wrapping();
+
+    // Key to this working is that the grid is square, the start is centered,
+    // and it has a clear run to the edge in all 4 directions
+    let centre = grid.height() / 2;
+
+    if grid.width() != grid.height()
+        || *start != Position::from((centre, centre))
+        || target_steps
+            .checked_sub(centre)
+            .is_none_or(|steps| !steps.is_multiple_of(grid.height()))
+    {
+        return None;
+    }
+
+    let target_grid_reach = (target_steps - start.y as usize) / grid.height();
+
+    let mut sequence = Vec::new();
+
+    // Basically, we are just going to calculate enoguh values the slow way
+    // to be able to extrapolate the final value
+    for grid_reach in 0.. {
+        let this_target_steps = grid.height() / 2 + grid_reach * grid.height();
+
+        if let Ok(polynomial) = Polynomial::fit(&sequence) {
+            trace!(
+                "fit",
+                degree = polynomial.degree(),
+                reach = target_grid_reach
+            );
+
+            return polynomial
+                .value_at(target_grid_reach as i128)
+                .ok()
+                .and_then(|plots| plots.try_into().ok());
+        } else {
+            let plots = count_possible_ending_tiles(&infinite_grid, this_target_steps, &[start]);
+
+            trace!(
+                "reach",
+                grids = grid_reach,
+                steps = this_target_steps,
+                plots = plots
+            );
+            sequence.push(plots as i64);
+        }
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::reference::{assert_agrees, Disagreement};
+
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example_after_6_steps() {
+        assert_eq!(solve_part1(&Day21::parse(INPUT_TEST).unwrap(), 6), 16);
+    }
+
+    #[test]
+    fn example_has_no_part2() {
+        // Its start is in the middle, but the steps don't end at the edge of a copy of it
+        assert_eq!(Day21::solve_part2(INPUT_TEST).unwrap(), Answer::Unavailable);
+        assert_eq!(Day21::solve_part2("S.\n..\n").unwrap(), Answer::Unavailable);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day21>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day21>(3);
+    }
+
+    #[test]
+    fn part2_extrapolates_like_counting_every_step() {
+        // Far too few steps to bother extrapolating for, but enough for there to be
+        // something to check against
+        assert_agrees::<Day21>(11, 0..10, |input| {
+            let garden = Day21::parse(input).unwrap();
+            let side = garden.grid.height();
+
+            (5..=8)
+                .map(|reach| side / 2 + reach * side)
+                .filter_map(|steps| {
+                    let expected = count_possible_ending_tiles(
+                        &garden.grid.wrapping(),
+                        steps,
+                        &[&garden.start],
+                    );
+                    let actual = solve_part2(&garden, steps).unwrap();
+
+                    (actual != expected).then(|| Disagreement {
+                        what: format!("{steps} steps"),
+                        expected: expected.into(),
+                        actual: actual.into(),
+                    })
+                })
+                .collect()
+        });
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day21>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day21::Day21;
+
+fn main() {
+    aoc_common::solution::main::<Day21>(env!("CARGO_MANIFEST_DIR"));
+}
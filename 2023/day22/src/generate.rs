@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day22;
+
+// As in the real input, every brick is within a 10 by 10 column
+const FOOTPRINT: i64 = 10;
+
+// `size` is the number of bricks. Each is a line of up to 4 cubes, and none overlap
+impl Generate for Day22 {
+    const DEFAULT_SIZE: usize = 1400;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // Enough height for the bricks to be spread out before they fall
+        let top = size as i64 / 3 + 10;
+
+        let mut filled = HashSet::new();
+        let mut bricks = Vec::new();
+
+        while bricks.len() < size {
+            let axis = rng.gen_range(0..3);
+            let length = rng.gen_range(0..4);
+
+            let from = [
+                rng.gen_range(0..FOOTPRINT),
+                rng.gen_range(0..FOOTPRINT),
+                rng.gen_range(1..=top),
+            ];
+            let mut to = from;
+            to[axis] += length;
+
+            let cubes = (0..=length)
+                .map(|i| {
+                    let mut cube = from;
+                    cube[axis] += i;
+                    cube
+                })
+                .collect::<Vec<_>>();
+
+            let outside = to[0] >= FOOTPRINT || to[1] >= FOOTPRINT;
+
+            if outside || cubes.iter().any(|cube| filled.contains(cube)) {
+                continue;
+            }
+
+            filled.extend(cubes);
+
+            let [x1, y1, z1] = from;
+            let [x2, y2, z2] = to;
+            bricks.push(format!("{x1},{y1},{z1}~{x2},{y2},{z2}"));
+        }
+
+        bricks.join("\n") + "\n"
+    }
+}
@@ -0,0 +1,271 @@
+mod generate;
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use aoc_common::{
+    geometry::{Offset3, Position3},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    // Sorted from lowest to highest
+    type Parsed<'input> = Vec<(Position3, Brick)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut initial_state = parse_initial_state(input).collect::<Result<Vec<_>, _>>()?;
+        initial_state.sort_by_key(|brick| brick.get_min_position().z);
+
+        Ok(initial_state)
+    }
+
+    fn part1(bricks: &Self::Parsed<'_>) -> Answer {
+        let (supporting, supported_by) = build_support_map(bricks);
+
+        let mut can_remove = 0usize;
+        'next: for i in 0..bricks.len() {
+            if let Some(s) = supporting.get(&i) {
+                if s.is_empty() {
+                    can_remove += 1;
+                    continue;
+                }
+
+                for other_brick_index in s.iter() {
+                    if let Some(s) = supported_by.get(other_brick_index) {
+                        // we're the only support
+                        if s.len() == 1 {
+                            continue 'next;
+                        }
+                    }
+                }
+            }
+
+            can_remove += 1;
+        }
+
+        can_remove.into()
+    }
+
+    fn part2(bricks: &Self::Parsed<'_>) -> Answer {
+        let (supporting, supported_by) = build_support_map(bricks);
+
+        let mut would_fall = HashMap::new();
+
+        for i in 0..bricks.len() {
+            let mut this_would_fall = HashSet::from([i]);
+            let mut recurse = BinaryHeap::from([Reverse(i)]);
+
+            while let Some(Reverse(i)) = recurse.pop() {
+                if let Some(s) = supporting.get(&i) {
+                    for other_brick_index in s.iter() {
+                        let this_supported_by = supported_by.get(other_brick_index).unwrap();
+
+                        if this_supported_by.len()
+                            == this_supported_by.intersection(&this_would_fall).count()
+                        {
+                            // all bricks supporting us are going to fall, so we will also fall
+                            this_would_fall.insert(*other_brick_index);
+                            recurse.push(Reverse(*other_brick_index));
+                        }
+                    }
+                }
+            }
+
+            trace!(
+                "disintegrate",
+                brick = i + 1,
+                would_fall = this_would_fall.len() - 1
+            );
+            would_fall.insert(i, this_would_fall);
+        }
+
+        would_fall
+            .values()
+            .map(|it| it.len() - 1)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn parse_initial_state(
+    input: &str,
+) -> impl Iterator<Item = Result<(Position3, Brick), ParseError>> + '_ {
+    parse::lines(input).map(|line| {
+        let (from, to) = line.split_once(line.text, "~")?;
+        let from = parse_position(&line, from)?;
+        let to = parse_position(&line, to)?;
+
+        let origin = from.component_min(to);
+        let extent = from.component_max(to) - origin;
+
+        let width = extent.x as usize + 1;
+        let depth = extent.y as usize + 1;
+        let height = extent.z as usize + 1;
+
+        let brick = Brick {
+            width,
+            depth,
+            height,
+        };
+
+        Ok((origin, brick))
+    })
+}
+
+fn build_support_map(
+    positioned_bricks: &[(Position3, Brick)],
+) -> (
+    HashMap<usize, HashSet<usize>>,
+    HashMap<usize, HashSet<usize>>,
+) {
+    let extents = positioned_bricks
+        .iter()
+        .map(|positioned_brick| positioned_brick.get_max_position())
+        .fold(Position3::default(), Position3::component_max);
+
+    // Stores the max height for that column and the index of the brick at that point
+    let mut max_occupancy_by_column =
+        vec![vec![None; extents.y as usize + 1]; extents.x as usize + 1];
+
+    let mut supporting = HashMap::new();
+    let mut supported_by = HashMap::new();
+    let mut supports = HashSet::<usize>::new();
+
+    for (i, positioned_brick) in positioned_bricks.iter().enumerate() {
+        let mut insert_height = 0;
+
+        for Position3 { x, y, z: _ } in positioned_brick.get_occupied_columns() {
+            let x = x as usize;
+            let y = y as usize;
+
+            if let Some((max_occupied, supporting_brick_index)) = max_occupancy_by_column[x][y] {
+                if max_occupied >= insert_height {
+                    insert_height = max_occupied + 1;
+                    supports.clear();
+                    supports.insert(supporting_brick_index);
+                } else if max_occupied == insert_height - 1 {
+                    supports.insert(supporting_brick_index);
+                }
+            }
+        }
+
+        for support in supports.iter() {
+            supporting
+                .entry(*support)
+                .or_insert_with(HashSet::new)
+                .insert(i);
+        }
+
+        trace!(
+            "settle",
+            brick = i + 1,
+            lands_at = insert_height,
+            resting_on = {
+                let mut below = supports.iter().map(|j| j + 1).collect::<Vec<_>>();
+                below.sort_unstable();
+                below
+            }
+        );
+
+        supported_by.insert(i, supports.clone());
+        supports.clear();
+
+        for Position3 { x, y, z: _ } in positioned_brick.get_occupied_columns() {
+            let x = x as usize;
+            let y = y as usize;
+            let insert_height = insert_height + positioned_brick.1.height - 1;
+
+            max_occupancy_by_column[x][y] = Some((insert_height, i));
+        }
+    }
+
+    (supporting, supported_by)
+}
+
+fn parse_position(line: &Line<'_>, s: &str) -> Result<Position3, ParseError> {
+    let [x, y, z] = line.parse_array(s, ",", "a coordinate")?;
+
+    Ok(Position3 { x, y, z })
+}
+
+pub struct Brick {
+    width: usize,
+    depth: usize,
+    height: usize,
+}
+
+trait Occupance {
+    fn get_min_position(&self) -> Position3;
+    fn get_max_position(&self) -> Position3;
+
+    fn get_occupied_columns(&self) -> impl Iterator<Item = Position3> + '_ {
+        let min = self.get_min_position();
+        let max = self.get_max_position();
+
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| Position3 { x, y, z: min.z }))
+    }
+}
+
+impl Occupance for (Position3, Brick) {
+    fn get_min_position(&self) -> Position3 {
+        self.0
+    }
+
+    fn get_max_position(&self) -> Position3 {
+        self.0
+            + Offset3 {
+                x: self.1.width as i64 - 1,
+                y: self.1.depth as i64 - 1,
+                z: self.1.height as i64 - 1,
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day22::solve_part1(INPUT_TEST).unwrap(), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day22::solve_part2(INPUT_TEST).unwrap(), 7);
+    }
+
+    #[test]
+    fn single_brick_along_y() {
+        // Its columns were once read with x and y swapped, off the side of the map
+        assert_eq!(Day22::solve_part1("1,0,1~1,2,1\n").unwrap(), 1);
+        assert_eq!(Day22::solve_part2("1,0,1~1,2,1\n").unwrap(), 0);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day22>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day22>(200);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day22>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
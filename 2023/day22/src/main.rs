@@ -0,0 +1,5 @@
+use advent_of_code_2023_day22::Day22;
+
+fn main() {
+    aoc_common::solution::main::<Day22>(env!("CARGO_MANIFEST_DIR"));
+}
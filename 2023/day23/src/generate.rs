@@ -0,0 +1,90 @@
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day23;
+
+// `size` is the number of junctions along each side of the map, at least 2. Trails run
+// between neighbouring junctions, on a grid, with slopes at each end sending walkers
+// right or down only, so that the slippery hike can't go round in circles. There is
+// always a trail all the way from the start to the end
+impl Generate for Day23 {
+    const DEFAULT_SIZE: usize = 6;
+    const MIN_SIZE: usize = 2;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // Where each column and row of junctions is; trails between them are at least
+        // 2 tiles long, to fit a slope at each end
+        let mut lines = || {
+            let mut at = vec![1];
+
+            for _ in 1..size {
+                at.push(at[at.len() - 1] + rng.gen_range(3..=8));
+            }
+
+            at
+        };
+        let (columns, rows) = (lines(), lines());
+
+        let width = columns[size - 1] + 2;
+        let height = rows[size - 1] + 2;
+
+        // Trails heading right and down from each junction
+        let mut right = vec![vec![false; size]; size];
+        let mut down = vec![vec![false; size]; size];
+
+        for y in 0..size {
+            for x in 0..size {
+                right[y][x] = x + 1 < size && rng.gen_ratio(3, 4);
+                down[y][x] = y + 1 < size && rng.gen_ratio(3, 4);
+            }
+        }
+
+        // A random staircase from the first junction to the last guarantees a way through
+        let (mut x, mut y) = (0, 0);
+
+        while (x, y) != (size - 1, size - 1) {
+            if y == size - 1 || (x < size - 1 && rng.gen()) {
+                right[y][x] = true;
+                x += 1;
+            } else {
+                down[y][x] = true;
+                y += 1;
+            }
+        }
+
+        let mut tiles = vec![vec!['#'; width]; height];
+        tiles[0][columns[0]] = '.';
+        tiles[height - 1][columns[size - 1]] = '.';
+
+        for j in 0..size {
+            for i in 0..size {
+                let (cx, cy) = (columns[i], rows[j]);
+                tiles[cy][cx] = '.';
+
+                if right[j][i] {
+                    let end = columns[i + 1];
+
+                    for tile in &mut tiles[cy][cx + 1..end] {
+                        *tile = '.';
+                    }
+
+                    tiles[cy][cx + 1] = '>';
+                    tiles[cy][end - 1] = '>';
+                }
+
+                if down[j][i] {
+                    let end = rows[j + 1];
+
+                    for row in &mut tiles[cy + 1..end] {
+                        row[cx] = '.';
+                    }
+
+                    tiles[cy + 1][cx] = 'v';
+                    tiles[end - 1][cx] = 'v';
+                }
+            }
+        }
+
+        generate::grid(rng, width, height, |_, x, y| tiles[y][x])
+    }
+}
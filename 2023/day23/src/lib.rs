@@ -0,0 +1,319 @@
+mod generate;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    geometry::{Direction, Position},
+    graph::{self, Successors},
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Parsed<'input> = Trails;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::try_parse(input, "`.`, `#` or a slope, one of `^v<>`", |c| match c {
+            '#' => Some(Tile::Forest),
+            '.' => Some(Tile::Path),
+            '^' => Some(Tile::Slope(Direction::Up)),
+            'v' => Some(Tile::Slope(Direction::Down)),
+            '<' => Some(Tile::Slope(Direction::Left)),
+            '>' => Some(Tile::Slope(Direction::Right)),
+            _ => None,
+        })?;
+
+        let start = find_start(&grid).ok_or_else(|| {
+            ParseError::new(
+                1,
+                1,
+                input.lines().next().unwrap_or_default(),
+                "expected a path in the top row",
+            )
+        })?;
+        let end = find_end(&grid).ok_or_else(|| {
+            let last = input.lines().last().unwrap_or_default();
+            ParseError::new(grid.height(), 1, last, "expected a path in the bottom row")
+        })?;
+
+        Ok(Trails { grid, start, end })
+    }
+
+    fn part1(trails: &Self::Parsed<'_>) -> Answer {
+        trails
+            .grid
+            .find_length_of_longest_path_between(&trails.start, &trails.end)
+            .map_or(Answer::Unavailable, Answer::from)
+    }
+
+    fn part2(trails: &Self::Parsed<'_>) -> Answer {
+        // The slopes aren't slippery after all
+        let grid = trails.grid.map(|tile| match tile {
+            Tile::Slope(_) => Tile::Path,
+            tile => *tile,
+        });
+
+        grid.find_length_of_longest_path_between_by_junctions(&trails.start, &trails.end)
+            .map_or(Answer::Unavailable, Answer::from)
+    }
+}
+
+pub struct Trails {
+    grid: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+fn get_next_allowed(
+    grid: &Grid<Tile>,
+    current_position: &Position,
+    predicate: impl Fn(&Position) -> bool,
+) -> Vec<Position> {
+    if let Tile::Slope(slope_direction) = grid[*current_position] {
+        let next_position = *current_position + slope_direction.as_offset();
+
+        // Slopes can lead off the edge of the map, or into the forest
+        if grid
+            .get(&next_position)
+            .is_some_and(|tile| *tile != Tile::Forest)
+            && predicate(&next_position)
+        {
+            vec![*current_position + slope_direction.as_offset()]
+        } else {
+            vec![]
+        }
+    } else {
+        grid.neighbours(current_position)
+            .filter(|next_position| {
+                grid[*next_position] != Tile::Forest && predicate(next_position)
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+fn find_start(grid: &Grid<Tile>) -> Option<Position> {
+    (0..grid.width() as i64)
+        .map(|x| Position { x, y: 0 })
+        .find(|position| grid[*position] == Tile::Path)
+}
+
+fn find_end(grid: &Grid<Tile>) -> Option<Position> {
+    let y = grid.height() as i64 - 1;
+
+    (0..grid.width() as i64)
+        .map(|x| Position { x, y })
+        .find(|position| grid[*position] == Tile::Path)
+}
+
+// The longest paths are `None` if there's no way from the start to the end
+trait Pathable {
+    fn find_length_of_longest_path_between(
+        &self,
+        start: &Position,
+        end: &Position,
+    ) -> Option<usize>;
+    fn build_junctions(&self, start: &Position, end: &Position) -> Junctions;
+    fn find_length_of_longest_path_between_by_junctions(
+        &self,
+        start: &Position,
+        end: &Position,
+    ) -> Option<usize>;
+}
+
+impl Pathable for Grid<Tile> {
+    fn find_length_of_longest_path_between(
+        &self,
+        start: &Position,
+        end: &Position,
+    ) -> Option<usize> {
+        let mut found = Vec::new();
+
+        let mut next_jobs = vec![(0, *start, HashSet::from([*start]))];
+
+        while let Some((distance, position, mut visited)) = next_jobs.pop() {
+            let mut nexts = get_next_allowed(self, &position, |next| !visited.contains(next));
+            let mut next_distance = distance + 1;
+
+            // avoid cloning visited when we only have one option
+            while nexts.len() == 1 {
+                let next = &nexts[0];
+
+                // Counted below
+                if next == end {
+                    break;
+                }
+
+                visited.insert(*next);
+
+                nexts = get_next_allowed(self, next, |next| !visited.contains(next));
+                next_distance += 1;
+            }
+
+            for next in nexts.iter() {
+                if next == end {
+                    trace!("hike", length = next_distance);
+                    found.push(next_distance);
+                    continue;
+                }
+
+                let mut visited = visited.clone();
+                visited.insert(*next);
+
+                next_jobs.push((next_distance, *next, visited));
+            }
+        }
+
+        found.into_iter().max()
+    }
+
+    fn build_junctions(&self, start: &Position, end: &Position) -> Junctions {
+        let mut junctions = HashSet::from([*start, *end]);
+
+        for x in 0..self.width() as i64 {
+            for y in 0..self.height() as i64 {
+                let position = Position { x, y };
+
+                if self[position] == Tile::Path
+                    && get_next_allowed(self, &position, |_| true).len() > 2
+                {
+                    junctions.insert(position);
+                }
+            }
+        }
+
+        junctions
+            .iter()
+            .map(|junction| {
+                // Walk the corridors leading out of this junction, stopping at the next ones
+                let neighbours = |position: &Position| {
+                    if position != junction && junctions.contains(position) {
+                        Vec::new()
+                    } else {
+                        get_next_allowed(self, position, |_| true)
+                    }
+                };
+
+                let nexts = graph::bfs_distances([*junction], &neighbours, None)
+                    .into_iter()
+                    .filter(|(position, _)| position != junction && junctions.contains(position))
+                    .collect::<HashMap<_, _>>();
+
+                trace!(
+                    "junction",
+                    at = (junction.x, junction.y),
+                    corridors = nexts.len()
+                );
+
+                (*junction, nexts)
+            })
+            .collect()
+    }
+
+    fn find_length_of_longest_path_between_by_junctions(
+        &self,
+        start: &Position,
+        end: &Position,
+    ) -> Option<usize> {
+        let junctions = self.build_junctions(start, end);
+
+        let mut max_found = None;
+        let mut jobs = vec![(0, *start, HashSet::from([*start]))];
+
+        while let Some((distance, position, visited)) = jobs.pop() {
+            for (next_position, next_distance) in junctions
+                .successors(&position)
+                .into_iter()
+                .filter(|(next, _)| !visited.contains(next))
+            {
+                let distance = next_distance + distance;
+
+                if next_position == *end {
+                    if max_found.is_none_or(|max_found| distance > max_found) {
+                        trace!("longer hike", length = distance, junctions = visited.len());
+                        max_found = Some(distance);
+                    }
+                } else {
+                    let mut visited = visited.clone();
+                    visited.insert(next_position);
+                    jobs.push((distance, next_position, visited));
+                }
+            }
+        }
+
+        max_found
+    }
+}
+
+// How far it is from each junction to each of the junctions it leads directly to
+type Junctions = HashMap<Position, HashMap<Position, usize>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day23::solve_part1(INPUT_TEST).unwrap(), 94);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day23::solve_part2(INPUT_TEST).unwrap(), 154);
+    }
+
+    #[test]
+    fn truncated_maps() {
+        let truncated = |lines| {
+            INPUT_TEST
+                .lines()
+                .take(lines)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        // The start is the end, then the slopes lead off the bottom of the map
+        for lines in [1, 5] {
+            assert_eq!(
+                Day23::solve_part1(&truncated(lines)).unwrap(),
+                Answer::Unavailable
+            );
+            assert_eq!(
+                Day23::solve_part2(&truncated(lines)).unwrap(),
+                Answer::Unavailable
+            );
+        }
+
+        assert_eq!(Day23::solve_part1(&truncated(7)).unwrap(), 20);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day23>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day23>(3);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day23>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day23::Day23;
+
+fn main() {
+    aoc_common::solution::main::<Day23>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,43 @@
+use aoc_common::generate::Generate;
+use rand::{rngs::StdRng, Rng};
+
+use crate::Day24;
+
+// `size` is the number of hailstones, at least 4. There is a rock that hits every one
+// of them, at whole numbers of nanoseconds, with positions and velocities of the same
+// sort of size as in the real input
+impl Generate for Day24 {
+    const DEFAULT_SIZE: usize = 300;
+    const MIN_SIZE: usize = 4;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let rock_position: [i64; 3] =
+            [(); 3].map(|_| rng.gen_range(100_000_000_000_000..400_000_000_000_000));
+        let rock_velocity: [i64; 3] = [(); 3].map(|_| rng.gen_range(-300..=300));
+
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let time = rng.gen_range(100_000_000_000..500_000_000_000i64);
+
+            // Hailstones never keep pace with the rock, or they could never collide
+            let velocity = rock_velocity.map(|rock| loop {
+                let velocity = rng.gen_range(-300..=300);
+
+                if velocity != rock {
+                    break velocity;
+                }
+            });
+
+            let position: [i64; 3] = std::array::from_fn(|axis| {
+                rock_position[axis] + (rock_velocity[axis] - velocity[axis]) * time
+            });
+
+            let [x, y, z] = position;
+            let [vx, vy, vz] = velocity;
+            input.push_str(&format!("{x}, {y}, {z} @ {vx}, {vy}, {vz}\n"));
+        }
+
+        input
+    }
+}
@@ -1,48 +1,74 @@
+mod generate;
+
 use nalgebra::*;
 use std::ops::{Add, Deref, DerefMut, Mul, Sub};
 
-const INPUT_TEST: &str = include_str!("../input_test.txt");
-const INPUT: &str = include_str!("../input.txt");
+use aoc_common::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+
+pub struct Day24;
 
-fn main() {
-    let test_result = solve_part1(INPUT_TEST, 7., 27.);
-    println!("Test Part 1: {}", test_result);
-    assert!(test_result == 2);
+impl Solution for Day24 {
+    const DAY: u8 = 24;
 
-    let part_1_result = solve_part1(INPUT, 200_000_000_000_000., 400_000_000_000_000.);
-    println!("Part 1: {}", part_1_result);
+    type Parsed<'input> = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::lines(input).map(Hailstone::try_from).collect()
+    }
 
-    let test_result = solve_part2(INPUT_TEST);
-    println!("Test Part 2: {}", test_result);
-    assert!(test_result == 47);
+    fn part1(hailstones: &Self::Parsed<'_>) -> Answer {
+        solve_part1(hailstones, 200_000_000_000_000., 400_000_000_000_000.).into()
+    }
 
-    let part_2_result = solve_part2(INPUT);
-    println!("Part 2: {}", part_2_result);
+    fn part2(hailstones: &Self::Parsed<'_>) -> Answer {
+        solve_part2(hailstones).map_or(Answer::Unavailable, Answer::from)
+    }
 }
 
-fn solve_part1(input: &str, min: f64, max: f64) -> usize {
-    let hailstones = input.lines().map(Hailstone::from).collect::<Vec<_>>();
+fn solve_part1(hailstones: &[Hailstone], min: f64, max: f64) -> usize {
     let pairs = hailstones.iter().enumerate().flat_map(|(i, hailstone)| {
         hailstones
             .iter()
+            .enumerate()
             .skip(i + 1)
-            .map(move |other| (hailstone, other))
+            .map(move |(j, other)| ((i, hailstone), (j, other)))
     });
 
     pairs
-        .filter_map(|(a, b)| a.get_intersection_xy_with(b).map(|it| (a, b, it)))
-        .filter(|(_, _, intersection)| {
-            (min <= intersection.x && intersection.x <= max)
-                && (min <= intersection.y && intersection.y <= max)
-        })
-        .filter(|(a, b, intersection)| {
-            a.intersection_is_in_future(intersection) && b.intersection_is_in_future(intersection)
+        .filter(|((i, a), (j, b))| {
+            let Some(intersection) = a.get_intersection_xy_with(b) else {
+                trace!(
+                    "pair",
+                    hailstones = (i + 1, j + 1),
+                    crossing = None::<(f64, f64)>
+                );
+                return false;
+            };
+
+            let inside = (min <= intersection.x && intersection.x <= max)
+                && (min <= intersection.y && intersection.y <= max);
+            let future = a.intersection_is_in_future(&intersection)
+                && b.intersection_is_in_future(&intersection);
+
+            trace!(
+                "pair",
+                hailstones = (i + 1, j + 1),
+                crossing = Some((intersection.x, intersection.y)),
+                inside = inside,
+                future = future
+            );
+
+            inside && future
         })
         .count()
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
-struct Hailstone {
+pub struct Hailstone {
     position: Position,
     velocity: Velocity,
 }
@@ -73,13 +99,15 @@ impl Hailstone {
     }
 }
 
-impl From<&str> for Hailstone {
-    fn from(input: &str) -> Self {
-        let (position, velocity) = input.split_once('@').unwrap();
-        let position = Position(Vector3::from(position));
-        let velocity = Velocity(Vector3::from(velocity));
+impl TryFrom<Line<'_>> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let (position, velocity) = line.split_once(line.text, "@")?;
+        let position = Position(Vector3::parse(&line, position)?);
+        let velocity = Velocity(Vector3::parse(&line, velocity)?);
 
-        Self { position, velocity }
+        Ok(Self { position, velocity })
     }
 }
 
@@ -90,16 +118,11 @@ struct Vector3 {
     z: f64,
 }
 
-impl From<&str> for Vector3 {
-    fn from(input: &str) -> Self {
-        match input.trim().split(',').collect::<Vec<_>>()[..] {
-            [x, y, z] => Self {
-                x: x.trim().parse().unwrap(),
-                y: y.trim().parse().unwrap(),
-                z: z.trim().parse().unwrap(),
-            },
-            _ => unreachable!(),
-        }
+impl Vector3 {
+    fn parse(line: &Line<'_>, input: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = line.parse_array(input, ",", "a number")?;
+
+        Ok(Self { x, y, z })
     }
 }
 
@@ -220,7 +243,9 @@ impl LineSegment {
     }
 }
 
-fn solve_part2(input: &str) -> usize {
+// `None` with too few hailstones to pin the rock down, or when they move in such a way
+// that they don't (such as in parallel)
+fn solve_part2(hailstones: &[Hailstone]) -> Option<usize> {
     // The starting position of the Rock can be represented as (RX,RY,RZ)
     // And its velocity as (RVX,RVY,RVZ)
     // The position of the Rock at some time t is therefore
@@ -292,12 +317,9 @@ fn solve_part2(input: &str) -> usize {
     //   A[coefficients left] * R[unknowns] = B[coefficients right]
     //   R = inv(A) * B
 
-    let hailstones = input
-        .lines()
-        .map(Hailstone::from)
-        .skip(1) // Probably float inaccuraccies, but the first hailstone causes the answer to be off by 4
-        .take(3) // We only need 3 for the answer
-        .collect::<Vec<_>>();
+    // Probably float inaccuraccies, but the first hailstone causes the answer to be off by 4.
+    // We only need 3 for the answer
+    let hailstones = hailstones.get(1..4)?;
 
     let c_xy_01 = coefficients_xy(&hailstones[0], &hailstones[1]);
     let c_xy_02 = coefficients_xy(&hailstones[0], &hailstones[2]);
@@ -313,10 +335,20 @@ fn solve_part2(input: &str) -> usize {
         c_xy_01.1, c_xy_02.1, c_xz_01.1, c_xz_02.1, c_yz_01.1, c_yz_02.1,
     );
 
-    let inv_a = a.try_inverse().unwrap();
+    let inv_a = a.try_inverse()?;
     let res = inv_a * b;
 
-    res.xyz().iter().sum::<f64>() as usize
+    if !res.iter().all(|value| value.is_finite()) {
+        return None;
+    }
+
+    trace!(
+        "rock",
+        position = (res[0], res[1], res[2]),
+        velocity = (res[3], res[4], res[5])
+    );
+
+    Some(res.xyz().iter().sum::<f64>() as usize)
 }
 
 fn coefficients_xy(a: &Hailstone, b: &Hailstone) -> (RowVector6<f64>, f64) {
@@ -366,3 +398,53 @@ fn coefficients_yz(a: &Hailstone, b: &Hailstone) -> (RowVector6<f64>, f64) {
             - a.position.z * a.velocity.y,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example_within_7_to_27() {
+        assert_eq!(solve_part1(&Day24::parse(INPUT_TEST).unwrap(), 7., 27.), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day24::solve_part2(INPUT_TEST).unwrap(), 47);
+    }
+
+    #[test]
+    fn too_few_or_parallel_hailstones_have_no_part2() {
+        let first_three = INPUT_TEST.lines().take(3).collect::<Vec<_>>().join("\n");
+        assert_eq!(
+            Day24::solve_part2(&first_three).unwrap(),
+            Answer::Unavailable
+        );
+
+        let parallel = "\
+0, 0, 0 @ 1, 1, 1
+1, 0, 0 @ 1, 1, 1
+2, 0, 0 @ 1, 1, 1
+3, 0, 0 @ 1, 1, 1
+";
+        assert_eq!(Day24::solve_part2(parallel).unwrap(), Answer::Unavailable);
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day24>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day24>(10);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day24>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,5 @@
+use advent_of_code_2023_day24::Day24;
+
+fn main() {
+    aoc_common::solution::main::<Day24>(env!("CARGO_MANIFEST_DIR"));
+}
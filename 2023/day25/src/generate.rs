@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use aoc_common::generate::{self, Generate};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use crate::Day25;
+
+// `size` is the number of components, at least 10, and no more than there are names of
+// three letters. They fall into two groups, each
+// joined up tightly enough that the three wires between the groups are the only
+// three that split the machine apart
+impl Generate for Day25 {
+    const DEFAULT_SIZE: usize = 1500;
+    const MIN_SIZE: usize = 10;
+    const MAX_SIZE: usize = 26 * 26 * 26;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let names = generate::names(rng, size, 3);
+        let split = rng.gen_range(size / 3..=size - size / 3).clamp(5, size - 5);
+        let groups = [&names[..split], &names[split..]];
+
+        let mut wires = BTreeSet::new();
+
+        for group in groups {
+            let n = group.len();
+
+            // Each component is wired to the next two round a ring, which takes cutting at
+            // least four wires to split up, plus a few more at random
+            for i in 0..n {
+                wires.insert(wire(&group[i], &group[(i + 1) % n]));
+                wires.insert(wire(&group[i], &group[(i + 2) % n]));
+
+                let other = group.choose(rng).unwrap();
+
+                if *other != group[i] {
+                    wires.insert(wire(&group[i], other));
+                }
+            }
+        }
+
+        let mut cut = 0;
+
+        while cut < 3 {
+            let a = groups[0].choose(rng).unwrap();
+            let b = groups[1].choose(rng).unwrap();
+
+            // Sharing a component would leave it hanging on by one fewer wire
+            let shared = wires
+                .iter()
+                .filter(|(x, y)| groups[0].contains(x) != groups[0].contains(y))
+                .any(|(x, y)| [x, y].contains(&a) || [x, y].contains(&b));
+
+            if !shared && wires.insert(wire(a, b)) {
+                cut += 1;
+            }
+        }
+
+        // Each wire is listed once, by whichever of its ends comes first
+        let mut lines = names
+            .iter()
+            .filter_map(|name| {
+                let others = wires
+                    .iter()
+                    .filter(|(a, _)| a == name)
+                    .map(|(_, b)| b.as_str())
+                    .collect::<Vec<_>>();
+
+                (!others.is_empty()).then(|| format!("{name}: {}", others.join(" ")))
+            })
+            .collect::<Vec<_>>();
+
+        lines.shuffle(rng);
+        lines.join("\n") + "\n"
+    }
+}
+
+fn wire(a: &str, b: &str) -> (String, String) {
+    let (a, b) = if a < b { (a, b) } else { (b, a) };
+    (a.to_string(), b.to_string())
+}
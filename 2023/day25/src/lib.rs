@@ -0,0 +1,281 @@
+#![deny(clippy::pedantic)]
+
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet},
+    iter,
+};
+
+use aoc_common::{
+    graph, parallel,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    trace,
+};
+use rand::distributions::{Distribution, Uniform};
+
+pub struct Day25;
+
+// How many random paths to take between each attempt at cutting the graph
+const SAMPLES: usize = 1000;
+
+// How many attempts to make before deciding there's no cut of three wires to find
+const MAX_ROUNDS: usize = 20;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Parsed<'input> = Vec<Edge<'input>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        build_edges(input)
+    }
+
+    fn part1(edges: &Self::Parsed<'_>) -> Answer {
+        // https://en.wikipedia.org/wiki/Karger%27s_algorithm
+        //
+        // This algorithm or some variant of it is probably the way to do this,
+        // but it didn't turn out to be fruitful for me.
+        // What DOES work most of the tinme has been to do the following:
+        //
+        //   The graph has two groups: Group 1 and Group 2. We don't know which nodes are in which group.
+        //   When picking two nodes at random, we can either pick two nodes from the same group, or one node from each group.
+        //   Assuming the same number of nodes in each group, the probability of picking two nodes from different groups is 1/2. (I guess? I'm not a statistician)
+        //   So, if we pick two nodes at random, and they're from different groups, we have traversed an edge that connects the two groups.
+        //   Since there's only 3 edges that connect the two groups, eventually we'll have traversed all 3 of them more often than other nodes.
+        //   Use that as a priority order for which nodes to try removing.
+        //
+        //   So our algorithm is therefore:
+        //     1. Pick two random nodes
+        //     2. Find the shortest path between them
+        //     3. Take note of the edges on that path
+        //     4. Repeat 1-3 a whole bunch of times
+        //     5. Order the edges by most-traversed first
+        //     6. Remove 3 edges at a time, and see if the graph is now split into 2 groups
+        //     7. If we failed to find the right combination, go back to 1 and try again:
+        //          a couple of iterations is usually enough to randomly land at the correct answer
+
+        let full_graph = gather_graph_from_edges(edges);
+        let all_nodes = full_graph.keys().collect::<Vec<_>>();
+
+        // There's nothing to cut the graph into two groups if it's in pieces already
+        if graph::connected_components(full_graph.keys().cloned(), &full_graph).len() != 1 {
+            return Answer::Unavailable;
+        }
+
+        let mut edge_use_count = HashMap::new();
+
+        // Each thread takes its own share of the samples, with its own random numbers
+        let threads = parallel::threads();
+        let batches = vec![SAMPLES.div_ceil(threads); threads];
+
+        for round in 1..=MAX_ROUNDS {
+            for counts in parallel::map(&batches, |&samples| {
+                sample_paths(samples, &all_nodes, &full_graph)
+            }) {
+                for (edge, count) in counts {
+                    *edge_use_count.entry(edge).or_insert(0) += count;
+                }
+            }
+
+            let cut_priorities = edge_use_count.drain().fold(
+                BinaryHeap::new(),
+                |mut max_heap, (edge, use_count)| {
+                    max_heap.push((use_count, edge));
+                    max_heap
+                },
+            );
+
+            let cut_priority = cut_priorities
+                .iter()
+                .map(|(_, edge)| edge)
+                .take(10)
+                .collect::<Vec<_>>();
+
+            trace!(
+                "round",
+                number = round,
+                busiest = cut_priority
+                    .iter()
+                    .map(|Edge(a, b)| format!("{}-{}", a.0, b.0))
+                    .collect::<Vec<_>>()
+            );
+
+            for i in 0..cut_priority.len() {
+                for j in i + 1..cut_priority.len() {
+                    for k in j + 1..cut_priority.len() {
+                        let mut edges_scratch = edges.clone();
+
+                        edges_scratch.retain(|edge| {
+                            edge != cut_priority[i]
+                                && edge != cut_priority[j]
+                                && edge != cut_priority[k]
+                        });
+
+                        let cut_graph = gather_graph_from_edges(&edges_scratch);
+                        let groups =
+                            graph::connected_components(cut_graph.keys().cloned(), &cut_graph);
+
+                        trace!("cut", edges = (i + 1, j + 1, k + 1), groups = groups.len());
+
+                        if groups.len() == 2 {
+                            return groups.iter().map(HashSet::len).product::<usize>().into();
+                        }
+                    }
+                }
+            }
+        }
+
+        Answer::Unavailable
+    }
+
+    // Turns out there is no part 2 of day 25
+    fn part2(_: &Self::Parsed<'_>) -> Answer {
+        Answer::Unavailable
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct NodeLabel<'a>(&'a str);
+
+impl<'a> From<&'a str> for NodeLabel<'a> {
+    fn from(s: &'a str) -> Self {
+        Self(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Edge<'a>(NodeLabel<'a>, NodeLabel<'a>);
+
+impl<'a> Edge<'a> {
+    fn new<T: Into<NodeLabel<'a>>>(nodes: (T, T)) -> Self {
+        let nodes = (nodes.0.into(), nodes.1.into());
+
+        if nodes.0 < nodes.1 {
+            Self(nodes.0, nodes.1)
+        } else {
+            Self(nodes.1, nodes.0)
+        }
+    }
+}
+
+// Counts how often each edge is on the shortest path between random pairs of nodes
+fn sample_paths<'a>(
+    samples: usize,
+    all_nodes: &[&NodeLabel<'a>],
+    graph: &HashMap<NodeLabel<'a>, Vec<NodeLabel<'a>>>,
+) -> HashMap<Edge<'a>, usize> {
+    let mut rng = rand::thread_rng();
+    let mut edge_use_count = HashMap::new();
+
+    let uniform_rng = Uniform::new(0, all_nodes.len());
+
+    for _ in 0..samples {
+        let start_index = uniform_rng.sample(&mut rng);
+        let goal_index = uniform_rng.sample(&mut rng);
+
+        // We're taking tonnes of samples so it's not a big deal
+        // if we get the same one twice and throw the entire sample away
+        if start_index == goal_index {
+            continue;
+        }
+
+        let goal = all_nodes[goal_index];
+        let Some(visited) = graph::bfs(all_nodes[start_index].clone(), graph, |node| node == goal)
+        else {
+            continue;
+        };
+
+        for i in 0..visited.len() - 1 {
+            let edge = Edge::new((visited[i].clone(), visited[i + 1].clone()));
+            *edge_use_count.entry(edge).or_insert(0) += 1;
+        }
+    }
+
+    edge_use_count
+}
+
+fn build_edges(input: &str) -> Result<Vec<Edge<'_>>, ParseError> {
+    let mut edges = HashSet::new();
+
+    for line in parse::lines(input) {
+        let (left, rights) = line.split_once(line.text, ":")?;
+
+        let left = left.trim();
+        let rights = rights.split_ascii_whitespace().collect::<Vec<_>>();
+
+        if left.is_empty() {
+            return Err(line.error(left, "expected a component name"));
+        }
+
+        if rights.is_empty() {
+            return Err(line.error_at_end("expected at least one connected component"));
+        }
+
+        edges.extend(iter::repeat(left).zip(rights).map(Edge::new));
+    }
+
+    Ok(edges.into_iter().collect())
+}
+
+fn gather_graph_from_edges<'graph, 'edge: 'graph>(
+    edges: &'graph [Edge<'edge>],
+) -> HashMap<NodeLabel<'edge>, Vec<NodeLabel<'edge>>> {
+    edges
+        .iter()
+        .fold(HashMap::new(), |mut acc, Edge(left, right)| {
+            acc.entry(left.clone()).or_default().push(right.clone());
+            acc.entry(right.clone()).or_default().push(left.clone());
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_TEST: &str = include_str!("../input_test.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day25::solve_part1(INPUT_TEST).unwrap(), 54);
+    }
+
+    #[test]
+    fn truncated_examples_have_no_answer() {
+        // A single component wired to three others, then graphs in pieces
+        for lines in [1, 2, 3, 6] {
+            let truncated = INPUT_TEST
+                .lines()
+                .take(lines)
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            assert_eq!(Day25::solve_part1(&truncated).unwrap(), Answer::Unavailable);
+        }
+    }
+
+    #[test]
+    fn truncated_inputs() {
+        aoc_common::generate::assert_truncated_inputs_solve::<Day25>(INPUT_TEST);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        aoc_common::generate::assert_generated_inputs_solve::<Day25>(30);
+    }
+
+    #[test]
+    fn matches_reference() {
+        aoc_common::reference::assert_matches_reference::<Day25>(0..10);
+    }
+
+    #[test]
+    #[ignore = "solves the full puzzle input"]
+    fn real_input() {
+        aoc_common::answers::assert_matches_recorded::<Day25>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,7 @@
+#![deny(clippy::pedantic)]
+
+use advent_of_code_2023_day25::Day25;
+
+fn main() {
+    aoc_common::solution::main::<Day25>(env!("CARGO_MANIFEST_DIR"));
+}
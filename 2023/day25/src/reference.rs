@@ -0,0 +1,69 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    reference::Reference,
+    solution::{Answer, Part},
+};
+
+use crate::Day25;
+
+// Tries cutting every three wires there are, until the machine falls into two pieces,
+// rather than guessing which wires to cut from the paths between random components
+impl Reference for Day25 {
+    const REFERENCE_SIZE: usize = 12;
+
+    fn reference(input: &str, part: Part) -> Answer {
+        if part == Part::Two {
+            return Answer::Unavailable;
+        }
+
+        let wires = input
+            .lines()
+            .flat_map(|line| {
+                let (from, to) = line.split_once(": ").unwrap();
+                to.split(' ').map(move |to| (from, to))
+            })
+            .collect::<Vec<_>>();
+
+        let n = wires.len();
+
+        for i in 0..n {
+            for j in i + 1..n {
+                for k in j + 1..n {
+                    let mut connected = HashMap::<&str, Vec<&str>>::new();
+
+                    for (index, &(a, b)) in wires.iter().enumerate() {
+                        if ![i, j, k].contains(&index) {
+                            connected.entry(a).or_default().push(b);
+                            connected.entry(b).or_default().push(a);
+                        }
+                    }
+
+                    // Everything reachable from any one component
+                    let mut piece = HashSet::from([wires[0].0]);
+                    let mut stack = vec![wires[0].0];
+
+                    while let Some(component) = stack.pop() {
+                        for next in connected.get(component).into_iter().flatten() {
+                            if piece.insert(next) {
+                                stack.push(next);
+                            }
+                        }
+                    }
+
+                    let components = wires
+                        .iter()
+                        .flat_map(|(a, b)| [*a, *b])
+                        .collect::<HashSet<_>>()
+                        .len();
+
+                    if piece.len() < components {
+                        return (piece.len() * (components - piece.len())).into();
+                    }
+                }
+            }
+        }
+
+        unreachable!("no three wires split the machine in two")
+    }
+}
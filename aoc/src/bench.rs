@@ -0,0 +1,153 @@
+use std::process;
+
+use aoc_common::{
+    bench::{self, Baseline, DayBench, Verdict},
+    input::InputSource,
+    solution::{format_duration, Part},
+};
+
+use crate::{
+    bundle_dir,
+    cli::{BenchOptions, Days},
+    days::Year,
+    read_input, report_parse_error, select_parts, select_runners,
+    table::Table,
+    year_dir,
+};
+
+pub fn bench(
+    year: &Year,
+    days: Days,
+    part: Option<Part>,
+    input: &InputSource,
+    options: &BenchOptions,
+) {
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "warning: benchmarking a debug build; use `cargo run --release` for real numbers"
+        );
+    }
+
+    let baseline_path = options
+        .baseline
+        .clone()
+        .unwrap_or_else(|| bench::baseline_path(&year_dir(year)));
+
+    let mut baseline = Baseline::load(&baseline_path).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    });
+
+    let parts = select_parts(part);
+
+    let mut benches = Vec::new();
+    let mut failed = false;
+
+    for runner in select_runners(year, days) {
+        let day = runner.day();
+
+        let Some(text) = read_input(year, day, input) else {
+            failed = true;
+            continue;
+        };
+        let input_key = input.answers_key(&bundle_dir(year, day).join("input.txt"), &text);
+
+        // Slow days can take a while, so show that something is happening
+        eprintln!("Benchmarking day {day}...");
+
+        match runner.bench(&text, &parts, &options.sampling) {
+            Ok(bench) => benches.push((bench, input_key)),
+            Err(error) => {
+                report_parse_error(year, day, input, &text, &error);
+                failed = true;
+            }
+        }
+    }
+
+    if !benches.is_empty() {
+        let (table, compared, regressions) = summarise(&benches, &baseline, options.threshold);
+
+        print!("{table}");
+
+        if compared == 0 {
+            println!(
+                "\nNothing to compare against in {}; use --save to record a baseline",
+                baseline_path.display()
+            );
+        } else {
+            println!(
+                "\n{regressions} of {compared} measurements regressed against {}",
+                baseline_path.display()
+            );
+        }
+
+        // So that scripts can catch a slowdown
+        failed |= regressions > 0;
+    }
+
+    if options.save && !benches.is_empty() {
+        for (bench, input_key) in &benches {
+            for (measure, stats) in &bench.measurements {
+                baseline.insert(bench.day, input_key, *measure, *stats);
+            }
+        }
+
+        match baseline.save(&baseline_path) {
+            Ok(()) => println!("Saved baseline to {}", baseline_path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// Also returns how many measurements had a baseline, and how many of those regressed
+// Each bench comes with the key of the input it was given
+fn summarise(
+    benches: &[(DayBench, String)],
+    baseline: &Baseline,
+    threshold: f64,
+) -> (Table, usize, usize) {
+    let header = [
+        "Day", "Measure", "Samples", "Mean", "Min", "Std dev", "Baseline", "Change",
+    ]
+    .map(String::from)
+    .to_vec();
+
+    let mut table = Table::new(header);
+    let mut compared = 0;
+    let mut regressions = 0;
+
+    for (bench, input_key) in benches {
+        for (measure, stats) in &bench.measurements {
+            let previous = baseline.get(bench.day, input_key, *measure);
+            let verdict = previous.map(|previous| Verdict::compare(stats, previous, threshold));
+
+            if let Some(verdict) = verdict {
+                compared += 1;
+
+                if verdict.is_regression() {
+                    regressions += 1;
+                }
+            }
+
+            table.push(vec![
+                bench.day.to_string(),
+                measure.to_string(),
+                stats.samples.to_string(),
+                format_duration(stats.mean),
+                format_duration(stats.min),
+                format_duration(stats.stddev),
+                previous.map_or_else(String::new, |previous| format_duration(previous.mean)),
+                verdict.map_or_else(String::new, |verdict| verdict.to_string()),
+            ]);
+        }
+    }
+
+    (table, compared, regressions)
+}
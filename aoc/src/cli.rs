@@ -0,0 +1,430 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc_common::{
+    bench::Sampling, input::InputSource, parallel::available_threads, solution::Part, trace,
+};
+
+pub const USAGE: &str = "\
+Usage: aoc run <DAY|all> [--year <YEAR>] [--part <1|2>] [--input <PATH>] [--json] [--record]
+               [--trace[=<PATH>]] [--parallel[=<N>]]
+       aoc bench <DAY|all> [--year <YEAR>] [--part <1|2>] [--input <PATH>] [BENCH OPTIONS]
+       aoc generate <DAY> [--year <YEAR>] [--seed <N>] [--size <N>]
+       aoc new <DAY> [--year <YEAR>]
+
+Commands:
+  run <DAY|all>    Run one day, or every day in turn, and print a summary
+  bench <DAY|all>  Time parsing and each part repeatedly, and compare the
+                   results with a saved baseline
+  generate <DAY>   Print a made up input for the day
+  new <DAY>        Start a new day: create its crate with an empty solution,
+                   tests and input files, and add it to the workspace and
+                   this runner
+
+Options:
+  -y, --year <YEAR>     Which year's puzzles to use [default: the latest year
+                        with solutions]
+  -p, --part <1|2>      Only run the given part
+  -i, --input <PATH>    Read the puzzle input from PATH, or `-` for stdin.
+                        Only valid when running a single day.
+                        Defaults to the day's bundled input.txt, or to
+                        the downloaded input if there isn't one
+  -h, --help            Print this message
+
+Run options:
+  --json                Print the answers and timings as JSON, including
+                        whether each answer matches answers.toml
+  --record              Save the answers to answers.toml as the known answers
+                        for the input, instead of failing on a mismatch
+  --trace[=<PATH>]      Log what each solution does along the way, one event
+                        per line, to stderr or to PATH
+  --parallel[=<N>]      Run days at the same time, and share the work within
+                        a day out where it can be, using up to N threads
+                        [default: one per CPU]. Answers are the same and in
+                        the same order, but timings are less reliable
+
+Bench options:
+  -n, --samples <N>       Time each measurement up to N times [default: 10]
+  --max-time <SECONDS>    Stop repeating a measurement once it has taken this
+                          long in total [default: 5]
+  --baseline <PATH>       Baseline to compare against and save to
+                          [default: bench-baseline.toml in the year's directory]
+  --threshold <PERCENT>   Change in mean time that counts as a regression
+                          [default: 10]
+  --save                  Save the results to the baseline afterwards
+
+Generate options:
+  --seed <N>    Seed for the random input; the same seed and size always give
+                the same input [default: 0]
+  --size <N>    How big an input to make, which means something different for
+                each day [default: about the size of the real input]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Days {
+    All,
+    One(u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Help,
+    Run {
+        year: Option<u16>,
+        days: Days,
+        part: Option<Part>,
+        input: InputSource,
+        json: bool,
+        record: bool,
+        trace: Option<trace::Target>,
+        // How many threads to use, if any more than one
+        parallel: Option<usize>,
+    },
+    Bench {
+        year: Option<u16>,
+        days: Days,
+        part: Option<Part>,
+        input: InputSource,
+        options: BenchOptions,
+    },
+    Generate {
+        year: Option<u16>,
+        day: u8,
+        seed: u64,
+        size: Option<usize>,
+    },
+    New {
+        year: Option<u16>,
+        day: u8,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub sampling: Sampling,
+    pub baseline: Option<PathBuf>,
+    // As a fraction, e.g. 0.1 for 10%
+    pub threshold: f64,
+    pub save: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            sampling: Sampling::default(),
+            baseline: None,
+            threshold: 0.1,
+            save: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingDay(&'static str),
+    InvalidDay(String),
+    InvalidYear(String),
+    InvalidPart(String),
+    InvalidValue(&'static str, String),
+    MissingValue(&'static str),
+    UnexpectedArgument(String),
+    InputWithAllDays,
+    OneDayOnly(&'static str),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "no command given"),
+            Self::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            Self::MissingDay(command) => write!(f, "`{command}` requires a day, or `all`"),
+            Self::InvalidDay(day) => write!(f, "invalid day `{day}`: expected 1-25 or `all`"),
+            Self::InvalidYear(year) => write!(f, "invalid year `{year}`: expected e.g. 2023"),
+            Self::InvalidPart(part) => write!(f, "invalid part `{part}`: expected 1 or 2"),
+            Self::InvalidValue(option, value) => {
+                write!(f, "invalid value `{value}` for `{option}`")
+            }
+            Self::MissingValue(option) => write!(f, "`{option}` requires a value"),
+            Self::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            Self::InputWithAllDays => write!(f, "`--input` can only be used with a single day"),
+            Self::OneDayOnly(command) => write!(f, "`{command}` only works on a single day"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        None => Err(CliError::MissingCommand),
+        Some("-h" | "--help" | "help") => Ok(Command::Help),
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("generate") => parse_generate(args),
+        Some("new") => parse_new(args),
+        Some(command) => Err(CliError::UnknownCommand(command.to_string())),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut selection = Selection::default();
+    let mut json = false;
+    let mut record = false;
+    let mut trace = None;
+    let mut parallel = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--json" => json = true,
+            "--record" => record = true,
+            "--trace" => trace = Some(trace::Target::Stderr),
+            "--parallel" => parallel = Some(available_threads()),
+            _ if arg.starts_with("--trace=") => {
+                trace = Some(trace::Target::File(PathBuf::from(&arg["--trace=".len()..])));
+            }
+            _ if arg.starts_with("--parallel=") => {
+                let value = &arg["--parallel=".len()..];
+                parallel = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|threads| *threads > 0)
+                        .ok_or_else(|| CliError::InvalidValue("--parallel", value.to_string()))?,
+                );
+            }
+            _ if selection.accept(&arg, &mut args)? => {}
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let (year, days, part, input) = selection.finish("run")?;
+
+    Ok(Command::Run {
+        year,
+        days,
+        part,
+        input,
+        json,
+        record,
+        trace,
+        parallel,
+    })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut selection = Selection::default();
+    let mut options = BenchOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-n" | "--samples" => {
+                options.sampling.max_samples = match parse_value(&mut args, "--samples")? {
+                    0 => return Err(CliError::InvalidValue("--samples", "0".to_string())),
+                    samples => samples,
+                };
+            }
+            "--max-time" => {
+                let seconds: f64 = parse_value(&mut args, "--max-time")?;
+                options.sampling.budget = Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| CliError::InvalidValue("--max-time", seconds.to_string()))?;
+            }
+            "--baseline" => {
+                let value = args.next().ok_or(CliError::MissingValue("--baseline"))?;
+                options.baseline = Some(PathBuf::from(value));
+            }
+            "--threshold" => {
+                let percent: f64 = parse_value(&mut args, "--threshold")?;
+
+                if percent.is_nan() || percent < 0. {
+                    return Err(CliError::InvalidValue("--threshold", percent.to_string()));
+                }
+
+                options.threshold = percent / 100.;
+            }
+            "--save" => options.save = true,
+            _ if selection.accept(&arg, &mut args)? => {}
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let (year, days, part, input) = selection.finish("bench")?;
+
+    Ok(Command::Bench {
+        year,
+        days,
+        part,
+        input,
+        options,
+    })
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut selection = Selection::default();
+    let mut seed = 0;
+    let mut size = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--seed" => seed = parse_value(&mut args, "--seed")?,
+            "--size" => size = Some(parse_value(&mut args, "--size")?),
+            _ if selection.accept_year_and_day(&arg, &mut args)? => {}
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let (year, day) = selection.finish_one_day("generate")?;
+
+    Ok(Command::Generate {
+        year,
+        day,
+        seed,
+        size,
+    })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut selection = Selection::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if selection.accept_year_and_day(&arg, &mut args)? => {}
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+
+    let (year, day) = selection.finish_one_day("new")?;
+
+    Ok(Command::New { year, day })
+}
+
+// Which days, parts and input to use; shared by every command
+#[derive(Default)]
+struct Selection {
+    year: Option<u16>,
+    days: Option<Days>,
+    part: Option<Part>,
+    input: Option<InputSource>,
+}
+
+impl Selection {
+    // Returns whether `arg` (and any value following it) was used
+    fn accept(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, CliError> {
+        if self.accept_year_and_day(arg, args)? {
+            return Ok(true);
+        }
+
+        match arg {
+            "-p" | "--part" => {
+                let value = args.next().ok_or(CliError::MissingValue("--part"))?;
+                self.part = Some(parse_part(&value)?);
+            }
+            "-i" | "--input" => {
+                let value = args.next().ok_or(CliError::MissingValue("--input"))?;
+                self.input = Some(match value.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::Path(PathBuf::from(path)),
+                });
+            }
+            _ if arg.starts_with("--part=") => {
+                self.part = Some(parse_part(&arg["--part=".len()..])?);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    // For commands that take no part or input
+    fn accept_year_and_day(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, CliError> {
+        match arg {
+            "-y" | "--year" => {
+                let value = args.next().ok_or(CliError::MissingValue("--year"))?;
+                self.year = Some(parse_year(&value)?);
+            }
+            _ if self.days.is_none() && !arg.starts_with('-') => {
+                self.days = Some(parse_days(arg)?);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    fn finish(
+        self,
+        command: &'static str,
+    ) -> Result<(Option<u16>, Days, Option<Part>, InputSource), CliError> {
+        let days = self.days.ok_or(CliError::MissingDay(command))?;
+        let input = self.input.unwrap_or(InputSource::Bundled);
+
+        if days == Days::All && input != InputSource::Bundled {
+            return Err(CliError::InputWithAllDays);
+        }
+
+        Ok((self.year, days, self.part, input))
+    }
+
+    fn finish_one_day(self, command: &'static str) -> Result<(Option<u16>, u8), CliError> {
+        match self.days.ok_or(CliError::MissingDay(command))? {
+            Days::All => Err(CliError::OneDayOnly(command)),
+            Days::One(day) => Ok((self.year, day)),
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &'static str,
+) -> Result<T, CliError> {
+    let value = args.next().ok_or(CliError::MissingValue(option))?;
+
+    value
+        .parse()
+        .map_err(|_| CliError::InvalidValue(option, value))
+}
+
+fn parse_days(value: &str) -> Result<Days, CliError> {
+    if value == "all" {
+        return Ok(Days::All);
+    }
+
+    match value.parse() {
+        Ok(day @ 1..=25) => Ok(Days::One(day)),
+        _ => Err(CliError::InvalidDay(value.to_string())),
+    }
+}
+
+// Advent of Code started in 2015
+fn parse_year(value: &str) -> Result<u16, CliError> {
+    match value.parse() {
+        Ok(year @ 2015..=9999) => Ok(year),
+        _ => Err(CliError::InvalidYear(value.to_string())),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, CliError> {
+    value
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| CliError::InvalidPart(value.to_string()))
+}
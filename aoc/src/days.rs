@@ -0,0 +1,58 @@
+use aoc_common::solution::{Runner, SolutionRunner};
+
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn Runner],
+}
+
+impl Year {
+    pub fn find(&self, day: u8) -> Option<&'static dyn Runner> {
+        self.days.iter().copied().find(|runner| runner.day() == day)
+    }
+}
+
+// Oldest first. `aoc new` adds days to this list, so keep to its layout
+#[rustfmt::skip]
+pub static YEARS: &[Year] = &[
+    Year {
+        year: 2023,
+        days: &[
+            &SolutionRunner::<y2023_day01::Day01>::new(),
+            &SolutionRunner::<y2023_day02::Day02>::new(),
+            &SolutionRunner::<y2023_day03::Day03>::new(),
+            &SolutionRunner::<y2023_day04::Day04>::new(),
+            &SolutionRunner::<y2023_day05::Day05>::new(),
+            &SolutionRunner::<y2023_day06::Day06>::new(),
+            &SolutionRunner::<y2023_day07::Day07>::new(),
+            &SolutionRunner::<y2023_day08::Day08>::new(),
+            &SolutionRunner::<y2023_day09::Day09>::new(),
+            &SolutionRunner::<y2023_day10::Day10>::new(),
+            &SolutionRunner::<y2023_day11::Day11>::new(),
+            &SolutionRunner::<y2023_day12::Day12>::new(),
+            &SolutionRunner::<y2023_day13::Day13>::new(),
+            &SolutionRunner::<y2023_day14::Day14>::new(),
+            &SolutionRunner::<y2023_day15::Day15>::new(),
+            &SolutionRunner::<y2023_day16::Day16>::new(),
+            &SolutionRunner::<y2023_day17::Day17>::new(),
+            &SolutionRunner::<y2023_day18::Day18>::new(),
+            &SolutionRunner::<y2023_day19::Day19>::new(),
+            &SolutionRunner::<y2023_day20::Day20>::new(),
+            &SolutionRunner::<y2023_day21::Day21>::new(),
+            &SolutionRunner::<y2023_day22::Day22>::new(),
+            &SolutionRunner::<y2023_day23::Day23>::new(),
+            &SolutionRunner::<y2023_day24::Day24>::new(),
+            &SolutionRunner::<y2023_day25::Day25>::new(),
+        ],
+    },
+];
+
+pub fn find(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+
+// The year used when none is given
+pub fn latest() -> &'static Year {
+    YEARS
+        .last()
+        .expect("there is at least one year of solutions")
+}
@@ -0,0 +1,311 @@
+mod bench;
+mod cli;
+mod days;
+mod scaffold;
+mod table;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
+
+use aoc_common::{
+    answers::{self, Answers, Status},
+    input::{InputError, InputSource},
+    json::Json,
+    parallel,
+    parse::{self, ParseError},
+    solution::{format_duration, Answer, Part, PartRun, Run, Runner},
+    trace,
+};
+
+use crate::{
+    cli::{Command, Days},
+    days::Year,
+    table::Table,
+};
+
+fn main() {
+    let command = match cli::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{}", cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            json,
+            record,
+            trace,
+            parallel: threads,
+        } => {
+            if let Some(threads) = threads {
+                parallel::enable(threads);
+            }
+
+            if let Some(target) = trace {
+                if let Err(error) = trace::start(&target) {
+                    eprintln!("error: could not start tracing: {error}");
+                    process::exit(1);
+                }
+            }
+
+            run(select_year(year), days, part, &input, json, record);
+        }
+        Command::Bench {
+            year,
+            days,
+            part,
+            input,
+            options,
+        } => bench::bench(select_year(year), days, part, &input, &options),
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+        } => {
+            let runner = select_runners(select_year(year), Days::One(day))[0];
+
+            match runner.generate(seed, size) {
+                Ok(input) => print!("{input}"),
+                Err(error) => {
+                    eprintln!("error: day {day}: {error}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::New { year, day } => {
+            let year = year.unwrap_or_else(|| days::latest().year);
+
+            if let Err(error) = scaffold::new_day(&workspace_dir(), year, day) {
+                eprintln!("error: {error}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// Each year's days are in a directory of their own in the workspace, along with the
+// answers to them
+fn year_dir(year: &Year) -> PathBuf {
+    workspace_dir().join(year.year.to_string())
+}
+
+fn bundle_dir(year: &Year, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day{day:02}"))
+}
+
+// Exits if there are no solutions for the year
+fn select_year(year: Option<u16>) -> &'static Year {
+    match year {
+        None => days::latest(),
+        Some(year) => days::find(year).unwrap_or_else(|| {
+            eprintln!("error: there are no solutions for {year}");
+            process::exit(1);
+        }),
+    }
+}
+
+// Exits if the day has no solution
+fn select_runners(year: &Year, days: Days) -> Vec<&'static dyn Runner> {
+    match days {
+        Days::All => year.days.to_vec(),
+        Days::One(day) => match year.find(day) {
+            Some(runner) => vec![runner],
+            None => {
+                eprintln!("error: there is no solution for day {day} of {}", year.year);
+                process::exit(1);
+            }
+        },
+    }
+}
+
+fn select_parts(part: Option<Part>) -> Vec<Part> {
+    part.map_or_else(|| Part::ALL.to_vec(), |part| vec![part])
+}
+
+// Prints the error and returns `None` if the input can't be read
+fn read_input(year: &Year, day: u8, input: &InputSource) -> Option<String> {
+    input
+        .read(&bundle_dir(year, day).join("input.txt"))
+        .inspect_err(|error| eprintln!("error: day {day}: {error}"))
+        .ok()
+}
+
+fn report_parse_error(year: &Year, day: u8, input: &InputSource, text: &str, error: &ParseError) {
+    let input_name = match input {
+        InputSource::Bundled => format!("{}/day{day:02}/input.txt", year.year),
+        input => input.name(),
+    };
+
+    eprintln!("{}", error.render(&input_name, &parse::normalise(text)));
+}
+
+fn run(year: &Year, days: Days, part: Option<Part>, input: &InputSource, json: bool, record: bool) {
+    let parts = select_parts(part);
+
+    let mut runs = Vec::new();
+    let mut failed = false;
+
+    let runners = select_runners(year, days);
+
+    // Days may be solved at the same time, but are always reported in order
+    let outcomes = parallel::map(&runners, |runner| {
+        let bundled = bundle_dir(year, runner.day()).join("input.txt");
+        let text = input.read(&bundled)?;
+        let input_key = input.answers_key(&bundled, &text);
+
+        Ok::<_, InputError>(
+            runner
+                .run(&text, &parts)
+                .map(|run| (run, input_key))
+                .map_err(|error| (text, error)),
+        )
+    });
+
+    for (runner, outcome) in runners.iter().zip(outcomes) {
+        let day = runner.day();
+
+        match outcome {
+            Ok(Ok(run)) => runs.push(run),
+            Ok(Err((text, error))) => {
+                report_parse_error(year, day, input, &text, &error);
+                failed = true;
+            }
+            Err(error) => {
+                eprintln!("error: day {day}: {error}");
+                failed = true;
+            }
+        }
+    }
+
+    let answers_path = year_dir(year).join("answers.toml");
+    let known = Answers::load_or_warn(&answers_path);
+
+    if json {
+        let records = runs
+            .iter()
+            .flat_map(|(run, input_key)| run.to_json(input_key, &known))
+            .collect();
+
+        println!("{}", Json::Array(records));
+    } else if !runs.is_empty() {
+        print!("{}", summarise(&runs, &parts, &known));
+
+        if runs.len() > 1 {
+            let total = runs
+                .iter()
+                .map(|(run, _)| run.total_elapsed())
+                .sum::<Duration>();
+            println!("\nTotal time: {}", format_duration(total));
+        }
+
+        report_statuses(&runs, &known);
+    }
+
+    let mismatched = runs.iter().any(|(run, input_key)| {
+        run.statuses(input_key, &known)
+            .any(|(_, status)| matches!(status, Status::Fail { .. }))
+    });
+
+    if record {
+        if let Err(error) = answers::record_runs(&answers_path, &runs) {
+            eprintln!("error: {error}");
+            failed = true;
+        }
+    } else if mismatched {
+        failed = true;
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// Each run comes with the key of the input it was given
+fn summarise(runs: &[(Run, String)], parts: &[Part], known: &Answers) -> Table {
+    let header = ["Day".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {part}")))
+        .chain(["Parse".to_string()])
+        .chain(parts.iter().map(|part| format!("Part {part} time")))
+        .chain(["Total".to_string()])
+        .collect();
+
+    let mut table = Table::new(header);
+
+    for (run, input) in runs {
+        let part_runs = parts
+            .iter()
+            .map(|part| run.part(*part).expect("part was not run"))
+            .collect::<Vec<_>>();
+
+        let answer_cell = |part_run: &PartRun| match &part_run.answer {
+            Answer::Unavailable => part_run.answer.to_string(),
+            answer => {
+                let status = known.check(run.day, input, part_run.part, answer);
+                format!("{answer} ({})", status.name())
+            }
+        };
+
+        let row = [run.day.to_string()]
+            .into_iter()
+            .chain(part_runs.iter().map(|part_run| answer_cell(part_run)))
+            .chain([format_duration(run.parse_elapsed)])
+            .chain(
+                part_runs
+                    .iter()
+                    .map(|part_run| format_duration(part_run.elapsed)),
+            )
+            .chain([format_duration(run.total_elapsed())])
+            .collect();
+
+        table.push(row);
+    }
+
+    table
+}
+
+// Spells out any mismatches, then totals up how every answer compared
+fn report_statuses(runs: &[(Run, String)], known: &Answers) {
+    let mut counts = [0; 3];
+
+    println!();
+
+    for (run, input) in runs {
+        for (part_run, status) in run.statuses(input, known) {
+            if part_run.answer == Answer::Unavailable {
+                continue;
+            }
+
+            match status {
+                Status::Pass => counts[0] += 1,
+                Status::Fail { .. } => {
+                    counts[1] += 1;
+                    println!(
+                        "Day {} part {}: {} ({status})",
+                        run.day, part_run.part, part_run.answer
+                    );
+                }
+                Status::Unknown => counts[2] += 1,
+            }
+        }
+    }
+
+    let [pass, fail, unknown] = counts;
+    println!("Answers: {pass} pass, {fail} fail, {unknown} unknown");
+}
@@ -0,0 +1,368 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+// Everything a new day starts with, relative to its directory
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.tmpl"),
+    ),
+    ("input_test.txt", ""),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+    // The file no longer looks the way `aoc new` expects it to, so it has to be
+    // edited by hand
+    Layout(PathBuf, &'static str),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "`{}` already exists", path.display()),
+            Self::Io(path, error) => write!(f, "`{}`: {error}", path.display()),
+            Self::Layout(path, expected) => {
+                write!(f, "`{}`: could not find {expected}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+// Creates the crate for a day from the templates, and wires it into the workspace and
+// the `aoc` runner. Nothing is written unless every file that needs changing could be
+pub fn new_day(workspace: &Path, year: u16, day: u8) -> Result<(), ScaffoldError> {
+    let name = format!("{year}/day{day:02}");
+    let dir = workspace.join(&name);
+
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    let workspace_manifest = workspace.join("Cargo.toml");
+    let aoc_manifest = workspace.join("aoc/Cargo.toml");
+    let days = workspace.join("aoc/src/days.rs");
+
+    let edits = [
+        (
+            add_member(&read(&workspace_manifest)?, year, day).ok_or(ScaffoldError::Layout(
+                workspace_manifest.clone(),
+                "`members`",
+            ))?,
+            workspace_manifest,
+        ),
+        (
+            add_dependency(&read(&aoc_manifest)?, year, day).ok_or(ScaffoldError::Layout(
+                aoc_manifest.clone(),
+                "`[dependencies]`",
+            ))?,
+            aoc_manifest,
+        ),
+        (
+            add_runner(&read(&days)?, year, day)
+                .ok_or(ScaffoldError::Layout(days.clone(), "`YEARS`"))?,
+            days,
+        ),
+    ];
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| fs::write(&path, fill(template, year, day)))
+            .map_err(|error| ScaffoldError::Io(path, error))?;
+    }
+
+    for (text, path) in edits {
+        fs::write(&path, text).map_err(|error| ScaffoldError::Io(path, error))?;
+    }
+
+    println!("Created {name}. Next:");
+    println!("  - put the example in {name}/input_test.txt");
+    println!("  - write the parser and both parts in {name}/src/lib.rs");
+    println!("  - run it with `aoc run {day} --year {year}`, which downloads the input");
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io(path.to_path_buf(), error))
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DD}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+// Lists `YEAR/dayNN` among the other days in the workspace manifest
+fn add_member(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+    let start = lines.iter().position(|line| line.trim() == "members = [")?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "]")?;
+
+    Some(insert_sorted(
+        lines,
+        start + 1..end,
+        |line| {
+            line.trim()
+                .trim_start_matches('"')
+                .starts_with(|c: char| c.is_ascii_digit())
+        },
+        &format!("    \"{year}/day{day:02}\","),
+    ))
+}
+
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")?;
+    let end = start
+        + 1
+        + lines[start + 1..]
+            .iter()
+            .position(|line| line.starts_with('['))
+            .unwrap_or(lines.len() - start - 1);
+
+    Some(insert_sorted(
+        lines,
+        start + 1..end,
+        |line| line.contains("package = \"advent-of-code-"),
+        &format!(
+            "y{year}_day{day:02} = {{ package = \"advent-of-code-{year}-day{day:02}\", \
+             path = \"../{year}/day{day:02}\" }}"
+        ),
+    ))
+}
+
+// Adds the day's runner to its year in `days.rs`, and the year itself if it is new
+fn add_runner(days: &str, year: u16, day: u8) -> Option<String> {
+    let mut lines = days.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static YEARS"))?;
+    let end = start + lines[start..].iter().position(|line| *line == "];")?;
+    let runner = format!("            &SolutionRunner::<y{year}_day{day:02}::Day{day:02}>::new(),");
+
+    let year_line = format!("        year: {year},");
+
+    if let Some(at) = lines[start..end].iter().position(|line| *line == year_line) {
+        let list = start + at + 1;
+
+        if lines[list].trim() != "days: &[" {
+            return None;
+        }
+
+        let list_end = list
+            + lines[list..end]
+                .iter()
+                .position(|line| line.trim() == "],")?;
+
+        return Some(insert_sorted(
+            lines,
+            list + 1..list_end,
+            |line| line.trim().starts_with("&SolutionRunner"),
+            &runner,
+        ));
+    }
+
+    // Before the first later year, to keep them oldest first
+    let at = (start..end)
+        .find(|&i| {
+            lines[i]
+                .trim()
+                .strip_prefix("year: ")
+                .and_then(|rest| rest.trim_end_matches(',').parse::<u16>().ok())
+                .is_some_and(|other| other > year)
+        })
+        .map_or(end, |i| i - 1);
+
+    let block = [
+        "    Year {".to_string(),
+        year_line,
+        "        days: &[".to_string(),
+        runner,
+        "        ],".to_string(),
+        "    },".to_string(),
+    ];
+    let block = block.iter().map(String::as_str).collect::<Vec<_>>();
+
+    lines.splice(at..at, block);
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Puts `new` among the entries in `range` so that they stay sorted, or at the end of
+// the range if there aren't any yet. Entries already there are left alone
+fn insert_sorted<'a>(
+    mut lines: Vec<&'a str>,
+    range: Range<usize>,
+    is_entry: impl Fn(&str) -> bool,
+    new: &'a str,
+) -> String {
+    if !lines[range.clone()].contains(&new) {
+        let mut entries = range.clone().filter(|&i| is_entry(lines[i]));
+        let first = entries.clone().next();
+        let at = entries
+            .rfind(|&i| lines[i] < new)
+            .map(|i| i + 1)
+            .or(first)
+            .unwrap_or(range.end);
+
+        lines.insert(at, new);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "\
+use aoc_common::solution::{Runner, SolutionRunner};
+
+pub static YEARS: &[Year] = &[
+    Year {
+        year: 2022,
+        days: &[
+            &SolutionRunner::<y2022_day01::Day01>::new(),
+        ],
+    },
+    Year {
+        year: 2023,
+        days: &[
+            &SolutionRunner::<y2023_day01::Day01>::new(),
+            &SolutionRunner::<y2023_day03::Day03>::new(),
+        ],
+    },
+];
+
+pub fn find(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+";
+
+    #[test]
+    fn adds_members_in_order() {
+        let manifest = "\
+[workspace]
+members = [
+    \"aoc\",
+    \"common\",
+    \"2023/day01\",
+    \"2023/day03\",
+]
+";
+
+        assert_eq!(
+            add_member(manifest, 2023, 2).unwrap(),
+            "\
+[workspace]
+members = [
+    \"aoc\",
+    \"common\",
+    \"2023/day01\",
+    \"2023/day02\",
+    \"2023/day03\",
+]
+"
+        );
+
+        assert!(add_member(&add_member(manifest, 2024, 1).unwrap(), 2024, 1)
+            .unwrap()
+            .ends_with("    \"2023/day03\",\n    \"2024/day01\",\n]\n"));
+
+        assert!(add_member(manifest, 2022, 25)
+            .unwrap()
+            .contains("    \"common\",\n    \"2022/day25\",\n    \"2023/day01\","));
+    }
+
+    #[test]
+    fn adds_dependencies_in_order() {
+        let manifest = "\
+[package]
+name = \"aoc\"
+
+[dependencies]
+aoc-common.workspace = true
+y2023_day01 = { package = \"advent-of-code-2023-day01\", path = \"../2023/day01\" }
+";
+
+        assert_eq!(
+            add_dependency(manifest, 2023, 2).unwrap(),
+            "\
+[package]
+name = \"aoc\"
+
+[dependencies]
+aoc-common.workspace = true
+y2023_day01 = { package = \"advent-of-code-2023-day01\", path = \"../2023/day01\" }
+y2023_day02 = { package = \"advent-of-code-2023-day02\", path = \"../2023/day02\" }
+"
+        );
+    }
+
+    #[test]
+    fn adds_runners_to_their_year() {
+        let days = add_runner(DAYS, 2023, 2).unwrap();
+
+        assert!(days.contains(
+            "&SolutionRunner::<y2023_day01::Day01>::new(),
+            &SolutionRunner::<y2023_day02::Day02>::new(),
+            &SolutionRunner::<y2023_day03::Day03>::new(),
+        ],"
+        ));
+    }
+
+    #[test]
+    fn adds_new_years_in_order() {
+        let expected = "Year {
+        year: 2021,
+        days: &[
+            &SolutionRunner::<y2021_day05::Day05>::new(),
+        ],
+    },
+    Year {
+        year: 2022,";
+
+        assert!(add_runner(DAYS, 2021, 5).unwrap().contains(expected));
+
+        let expected = "Year {
+        year: 2024,
+        days: &[
+            &SolutionRunner::<y2024_day01::Day01>::new(),
+        ],
+    },
+];";
+
+        assert!(add_runner(DAYS, 2024, 1).unwrap().contains(expected));
+    }
+
+    #[test]
+    fn fills_in_templates() {
+        assert_eq!(
+            fill(TEMPLATES[1].1, 2024, 7),
+            "\
+use advent_of_code_2024_day07::Day07;
+
+fn main() {
+    aoc_common::solution::main::<Day07>(env!(\"CARGO_MANIFEST_DIR\"));
+}
+"
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    parse,
+    solution::{Answer, Part, Run, Solution},
+};
+
+// Known-correct answers, keyed by day, input name and part. Stored as a small
+// subset of TOML:
+//
+//   [day01.input]
+//   part1 = 54159
+//   part2 = "text answers are quoted"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    recorded: BTreeMap<AnswerKey, Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+}
+
+// How an answer compares with the one on record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail { .. } => "fail",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            status => write!(f, "{}", status.name()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    Syntax { line: usize, message: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, error) => {
+                write!(
+                    f,
+                    "could not read answers file `{}`: {error}",
+                    path.display()
+                )
+            }
+            Self::Write(path, error) => {
+                write!(
+                    f,
+                    "could not write answers file `{}`: {error}",
+                    path.display()
+                )
+            }
+            Self::Syntax { line, message } => write!(f, "answers file line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| AnswersError::Read(path.to_path_buf(), error))?;
+
+        Self::parse(&text)
+    }
+
+    // For when known answers are nice to have: a missing file means there are none,
+    // and any other problem is reported as a warning
+    pub fn load_or_warn(path: &Path) -> Self {
+        match Self::load(path) {
+            Ok(answers) => answers,
+            Err(AnswersError::Read(_, error)) if error.kind() == io::ErrorKind::NotFound => {
+                Self::default()
+            }
+            Err(error) => {
+                eprintln!("warning: {error}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let mut recorded = BTreeMap::new();
+        let mut table = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let syntax_error = |message: String| AnswersError::Syntax {
+                line: i + 1,
+                message,
+            };
+
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| syntax_error("unterminated table header".to_string()))?;
+
+                table = Some(parse_table_header(header).map_err(syntax_error)?);
+                continue;
+            }
+
+            let (day, input) = table.clone().ok_or_else(|| {
+                syntax_error("answer outside of a [dayNN.input] table".to_string())
+            })?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| syntax_error(format!("expected `key = value`, found `{line}`")))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(syntax_error(format!("unknown key `{key}`"))),
+            };
+
+            let answer = parse_value(value.trim()).map_err(syntax_error)?;
+
+            recorded.insert(AnswerKey { day, input, part }, answer);
+        }
+
+        Ok(Self { recorded })
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.recorded.get(&AnswerKey {
+            day,
+            input: input.to_string(),
+            part,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&AnswerKey, &Answer)> {
+        self.recorded.iter()
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: Part, answer: &Answer) -> Status {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        }
+    }
+
+    // Returns the answer that was previously recorded, if any
+    pub fn record(&mut self, day: u8, input: &str, part: Part, answer: Answer) -> Option<Answer> {
+        let key = AnswerKey {
+            day,
+            input: input.to_string(),
+            part,
+        };
+
+        self.recorded.insert(key, answer)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string())
+            .map_err(|error| AnswersError::Write(path.to_path_buf(), error))
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Known answers, checked by the binaries and by `cargo test -- --ignored`.\n\
+             # Update them with `--record`."
+        )?;
+
+        let mut table = None;
+
+        for (key, answer) in &self.recorded {
+            if table != Some((key.day, &key.input)) {
+                table = Some((key.day, &key.input));
+                writeln!(f, "\n[day{:02}.{}]", key.day, table_key(&key.input))?;
+            }
+
+            match answer {
+                Answer::Number(number) => writeln!(f, "part{} = {number}", key.part)?,
+                Answer::Text(text) => writeln!(f, "part{} = \"{}\"", key.part, escape(text))?,
+                Answer::Unavailable => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Records every answer in `runs` against the input each was keyed by in the answers
+// file at `path`, reporting what changed on stderr. Parts without an answer are skipped
+pub fn record_runs(path: &Path, runs: &[(Run, String)]) -> Result<(), AnswersError> {
+    let mut answers = match Answers::load(path) {
+        Err(AnswersError::Read(_, error)) if error.kind() == io::ErrorKind::NotFound => {
+            Answers::default()
+        }
+        answers => answers?,
+    };
+
+    for (run, input) in runs {
+        for part_run in &run.parts {
+            if part_run.answer == Answer::Unavailable {
+                continue;
+            }
+
+            let previous = answers.record(run.day, input, part_run.part, part_run.answer.clone());
+            let (day, part, answer) = (run.day, part_run.part, &part_run.answer);
+
+            match previous {
+                Some(previous) if previous == *answer => {}
+                Some(previous) => {
+                    eprintln!("Recorded day {day} part {part} = {answer} (was {previous})")
+                }
+                None => eprintln!("Recorded day {day} part {part} = {answer}"),
+            }
+        }
+    }
+
+    answers.save(path)
+}
+
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_table_header(header: &str) -> Result<(u8, String), String> {
+    let expected = || format!("expected a [dayNN.input] table, found [{header}]");
+
+    let (day, input) = header.trim().split_once('.').ok_or_else(expected)?;
+
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| format!("invalid day `{day}`"))?;
+
+    match parse_table_key(input)? {
+        (input, "") => Ok((day, input)),
+        _ => Err(expected()),
+    }
+}
+
+fn is_bare_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+// An input's key as it's written in a table header, quoted unless it's a bare TOML key,
+// e.g. `input` but `"big.v2"`
+pub(crate) fn table_key(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(is_bare_key_char) {
+        key.to_string()
+    } else {
+        format!("\"{}\"", escape(key))
+    }
+}
+
+// Reads a key written by `table_key` from the start of `text`, returning the rest
+pub(crate) fn parse_table_key(text: &str) -> Result<(String, &str), String> {
+    let Some(quoted) = text.strip_prefix('"') else {
+        let end = text.find(|c| !is_bare_key_char(c)).unwrap_or(text.len());
+
+        return match &text[..end] {
+            "" => Err(format!("expected an input name, found `{text}`")),
+            key => Ok((key.to_string(), &text[end..])),
+        };
+    };
+
+    let mut escaped = false;
+
+    for (i, c) in quoted.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => {
+                let key =
+                    unescape(&quoted[..=i]).map_err(|message| format!("{message} in `{text}`"))?;
+                return Ok((key, &quoted[i + 1..]));
+            }
+            _ => {}
+        }
+    }
+
+    Err(format!("unterminated input name `{text}`"))
+}
+
+fn parse_value(value: &str) -> Result<Answer, String> {
+    if let Some(text) = value.strip_prefix('"') {
+        return unescape(text)
+            .map(Answer::Text)
+            .map_err(|message| format!("{message} in `{value}`"));
+    }
+
+    value
+        .replace('_', "")
+        .parse()
+        .map(Answer::Number)
+        .map_err(|_| format!("expected a number or a quoted string, found `{value}`"))
+}
+
+// Text answers are written as TOML strings, so quotes and backslashes are escaped
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// The rest of a string after its opening quote, which must end at its closing quote.
+// Only the escapes `escape` writes are understood
+fn unescape(text: &str) -> Result<String, String> {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(unescaped),
+            '"' => return Err("unexpected text after string".to_string()),
+            '\\' => match chars.next() {
+                Some(c @ ('"' | '\\')) => unescaped.push(c),
+                _ => return Err("unsupported escape".to_string()),
+            },
+            c => unescaped.push(c),
+        }
+    }
+
+    Err("unterminated string".to_string())
+}
+
+// The answers file lives in the year's directory, next to its days
+pub fn answers_path(bundle_dir: &Path) -> PathBuf {
+    bundle_dir.join("..").join("answers.toml")
+}
+
+// Solves the bundled input.txt and panics if any part disagrees with the answers file
+pub fn assert_matches_recorded<S: Solution>(bundle_dir: &str) {
+    let bundle_dir = Path::new(bundle_dir);
+
+    let answers =
+        Answers::load(&answers_path(bundle_dir)).unwrap_or_else(|error| panic!("{error}"));
+
+    let input_path = bundle_dir.join("input.txt");
+    let input = fs::read_to_string(&input_path)
+        .unwrap_or_else(|error| panic!("could not read `{}`: {error}", input_path.display()));
+
+    let input = parse::normalise(&input);
+    let parsed = S::parse(&input).unwrap_or_else(|error| panic!("{error}"));
+    let mut checked = 0;
+
+    for part in Part::ALL {
+        let Some(expected) = answers.get(S::DAY, "input", part) else {
+            continue;
+        };
+
+        let actual = S::part(&parsed, part);
+
+        assert_eq!(&actual, expected, "day {} part {part}", S::DAY);
+        checked += 1;
+    }
+
+    assert!(checked > 0, "no answers recorded for day {}", S::DAY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tables_numbers_and_strings() {
+        let answers = Answers::parse(
+            "# comment\n\
+             [day01.input]\n\
+             part1 = 54_159 # trailing comment\n\
+             \n\
+             [day01.example]\n\
+             part2 = \"ABC # not a comment\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.get(1, "input", Part::One),
+            Some(&Answer::Number(54159))
+        );
+        assert_eq!(answers.get(1, "input", Part::Two), None);
+        assert_eq!(
+            answers.get(1, "example", Part::Two),
+            Some(&Answer::from("ABC # not a comment"))
+        );
+    }
+
+    #[test]
+    fn written_answers_read_back_the_same() {
+        let mut answers = Answers::default();
+        answers.record(7, "input", Part::Two, Answer::from(5905));
+        answers.record(7, "input", Part::One, Answer::from(6440));
+        answers.record(7, "input_test", Part::One, Answer::from("ABC"));
+
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+        assert!(answers.to_string().ends_with(
+            "[day07.input]\npart1 = 6440\npart2 = 5905\n\n[day07.input_test]\npart1 = \"ABC\"\n"
+        ));
+    }
+
+    #[test]
+    fn text_answers_are_escaped() {
+        let mut answers = Answers::default();
+        answers.record(
+            1,
+            "input",
+            Part::One,
+            Answer::from(r#"say "hi" \ # not a comment"#),
+        );
+
+        assert!(answers
+            .to_string()
+            .ends_with("part1 = \"say \\\"hi\\\" \\\\ # not a comment\"\n"));
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+
+        for value in [r#""a\""#, r#""a\n""#, r#""a" b"#] {
+            assert!(Answers::parse(&format!("[day01.input]\npart1 = {value}\n")).is_err());
+        }
+    }
+
+    #[test]
+    fn input_names_are_quoted_where_needed() {
+        let mut answers = Answers::default();
+        answers.record(7, "input", Part::One, Answer::from(1));
+        answers.record(7, "big.v2-00ff", Part::One, Answer::from(2));
+        answers.record(7, "say \"hi\" # ]", Part::One, Answer::from(3));
+
+        let text = answers.to_string();
+
+        assert!(text.contains("[day07.input]\n"));
+        assert!(text.contains("[day07.\"big.v2-00ff\"]\n"));
+        assert!(text.contains("[day07.\"say \\\"hi\\\" # ]\"]\n"));
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+
+        for header in ["[day07.\"a\"b]", "[day07.a.b]", "[day07.\"a]", "[day07.]"] {
+            assert!(Answers::parse(&format!("{header}\npart1 = 1\n")).is_err());
+        }
+    }
+
+    #[test]
+    fn checks_answers_against_the_record() {
+        let answers = Answers::parse("[day07.input]\npart1 = 6440\n").unwrap();
+
+        assert_eq!(
+            answers.check(7, "input", Part::One, &Answer::from(6440)),
+            Status::Pass
+        );
+        assert_eq!(
+            answers.check(7, "input", Part::One, &Answer::from(1)),
+            Status::Fail {
+                expected: Answer::from(6440)
+            }
+        );
+        assert_eq!(
+            answers.check(7, "input", Part::Two, &Answer::from(5905)),
+            Status::Unknown
+        );
+    }
+
+    #[test]
+    fn reports_the_line_of_a_syntax_error() {
+        let error = Answers::parse("[day01.input]\npart1 = 1\npart3 = 2\n").unwrap_err();
+
+        assert!(matches!(error, AnswersError::Syntax { line: 3, .. }));
+    }
+}
@@ -0,0 +1,420 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs, hint, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{parse_table_key, strip_comment, table_key},
+    solution::Part,
+};
+
+// How many times to repeat each measurement. Sampling stops early once a
+// measurement has used up its budget, so that slow days still finish
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sampling {
+    pub max_samples: usize,
+    pub budget: Duration,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self {
+            max_samples: 10,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Sampling {
+    // Always takes at least one sample
+    pub fn measure<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        let mut samples = Vec::new();
+        let mut spent = Duration::ZERO;
+
+        while samples.is_empty() || (samples.len() < self.max_samples && spent < self.budget) {
+            let start = Instant::now();
+            hint::black_box(f());
+            let elapsed = start.elapsed();
+
+            samples.push(elapsed);
+            spent += elapsed;
+        }
+
+        Stats::from_samples(&samples)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+
+        // Sample standard deviation; a single sample has none
+        let stddev = if nanos.len() > 1 {
+            let variance =
+                nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.
+        };
+
+        Self {
+            samples: samples.len(),
+            mean: Duration::from_nanos(mean.round() as u64),
+            min: *samples.iter().min().unwrap(),
+            stddev: Duration::from_nanos(stddev.round() as u64),
+        }
+    }
+}
+
+// What was timed: parsing the input, or solving one part from the parsed input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Measure {
+    Parse,
+    Part(Part),
+}
+
+impl Measure {
+    fn key(&self) -> String {
+        match self {
+            Self::Parse => "parse".to_string(),
+            Self::Part(part) => format!("part{part}"),
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Self::Parse),
+            "part1" => Some(Self::Part(Part::One)),
+            "part2" => Some(Self::Part(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Measure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub measurements: Vec<(Measure, Stats)>,
+}
+
+// How a measurement compares with its baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Regressed(f64),
+    Improved(f64),
+    Unchanged(f64),
+}
+
+impl Verdict {
+    // `threshold` is the fractional change in mean time that counts, e.g. 0.1 for 10%
+    pub fn compare(current: &Stats, baseline: &Stats, threshold: f64) -> Self {
+        let baseline_mean = baseline.mean.as_nanos().max(1) as f64;
+        let change = (current.mean.as_nanos() as f64 - baseline_mean) / baseline_mean;
+
+        if change > threshold {
+            Self::Regressed(change)
+        } else if change < -threshold {
+            Self::Improved(change)
+        } else {
+            Self::Unchanged(change)
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Self::Regressed(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Regressed(change) => write!(f, "{:+.1}% regressed", change * 100.),
+            Self::Improved(change) => write!(f, "{:+.1}% improved", change * 100.),
+            Self::Unchanged(change) => write!(f, "{:+.1}%", change * 100.),
+        }
+    }
+}
+
+// Saved results to compare later runs against, keyed by day and input as the answers
+// file is. Stored in the same TOML subset, with times in nanoseconds:
+//
+//   [day05.input.parse]
+//   samples = 10
+//   mean = 36300
+//   min = 35100
+//   stddev = 900
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    recorded: BTreeMap<(u8, String, Measure), Stats>,
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    Syntax { line: usize, message: String },
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, error) => {
+                write!(f, "could not read baseline `{}`: {error}", path.display())
+            }
+            Self::Write(path, error) => {
+                write!(f, "could not write baseline `{}`: {error}", path.display())
+            }
+            Self::Syntax { line, message } => write!(f, "baseline line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    // A missing file is an empty baseline
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(BaselineError::Read(path.to_path_buf(), error)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_string())
+            .map_err(|error| BaselineError::Write(path.to_path_buf(), error))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, BaselineError> {
+        // samples, mean, min and stddev for each table, along with the line its header is on
+        type Fields = [Option<u64>; 4];
+        let mut tables: BTreeMap<(u8, String, Measure), (usize, Fields)> = BTreeMap::new();
+        let mut table = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let syntax_error = |message: String| BaselineError::Syntax {
+                line: i + 1,
+                message,
+            };
+
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let key = header
+                    .strip_suffix(']')
+                    .and_then(|header| {
+                        // Input keys are quoted if they have dots of their own
+                        let (day, rest) = header.split_once('.')?;
+                        let (input, rest) = parse_table_key(rest).ok()?;
+                        let measure = rest.strip_prefix('.')?;
+                        let day = day.strip_prefix("day")?.parse().ok()?;
+                        Some((day, input, Measure::from_key(measure)?))
+                    })
+                    .ok_or_else(|| {
+                        syntax_error(format!(
+                            "expected a [dayNN.input.measure] table, found {line}"
+                        ))
+                    })?;
+
+                tables.insert(key.clone(), (i + 1, [None; 4]));
+                table = Some(key);
+                continue;
+            }
+
+            let (_, fields) = table
+                .as_ref()
+                .and_then(|key| tables.get_mut(key))
+                .ok_or_else(|| syntax_error("value outside of a table".to_string()))?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| syntax_error(format!("expected `key = value`, found `{line}`")))?;
+
+            let field = match key.trim() {
+                "samples" => &mut fields[0],
+                "mean" => &mut fields[1],
+                "min" => &mut fields[2],
+                "stddev" => &mut fields[3],
+                key => return Err(syntax_error(format!("unknown key `{key}`"))),
+            };
+
+            *field = Some(
+                value
+                    .trim()
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| syntax_error(format!("expected a number, found `{value}`")))?,
+            );
+        }
+
+        let recorded = tables
+            .into_iter()
+            .map(|((day, input, measure), (line, fields))| {
+                let [Some(samples), Some(mean), Some(min), Some(stddev)] = fields else {
+                    return Err(BaselineError::Syntax {
+                        line,
+                        message: "expected samples, mean, min and stddev".to_string(),
+                    });
+                };
+
+                let stats = Stats {
+                    samples: samples as usize,
+                    mean: Duration::from_nanos(mean),
+                    min: Duration::from_nanos(min),
+                    stddev: Duration::from_nanos(stddev),
+                };
+
+                Ok(((day, input, measure), stats))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { recorded })
+    }
+
+    pub fn get(&self, day: u8, input: &str, measure: Measure) -> Option<&Stats> {
+        self.recorded.get(&(day, input.to_string(), measure))
+    }
+
+    pub fn insert(&mut self, day: u8, input: &str, measure: Measure, stats: Stats) {
+        self.recorded
+            .insert((day, input.to_string(), measure), stats);
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Written by `aoc bench --save`; times are in nanoseconds"
+        )?;
+
+        for ((day, input, measure), stats) in &self.recorded {
+            writeln!(f)?;
+            writeln!(f, "[day{day:02}.{}.{}]", table_key(input), measure.key())?;
+            writeln!(f, "samples = {}", stats.samples)?;
+            writeln!(f, "mean = {}", stats.mean.as_nanos())?;
+            writeln!(f, "min = {}", stats.min.as_nanos())?;
+            writeln!(f, "stddev = {}", stats.stddev.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+// The baseline lives alongside the days it times unless told otherwise
+pub fn baseline_path(dir: &Path) -> PathBuf {
+    dir.join("bench-baseline.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(mean: u64) -> Stats {
+        Stats {
+            samples: 3,
+            mean: Duration::from_nanos(mean),
+            min: Duration::from_nanos(mean - 10),
+            stddev: Duration::from_nanos(5),
+        }
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let samples = [100, 200, 300].map(Duration::from_nanos);
+
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                samples: 3,
+                mean: Duration::from_nanos(200),
+                min: Duration::from_nanos(100),
+                stddev: Duration::from_nanos(100),
+            }
+        );
+    }
+
+    #[test]
+    fn stops_sampling_once_the_budget_is_spent() {
+        let sampling = Sampling {
+            max_samples: 100,
+            budget: Duration::ZERO,
+        };
+
+        assert_eq!(sampling.measure(|| ()).samples, 1);
+    }
+
+    #[test]
+    fn baseline_round_trips() {
+        let mut baseline = Baseline::default();
+        baseline.insert(5, "input", Measure::Parse, stats(36_300));
+        baseline.insert(5, "input", Measure::Part(Part::Two), stats(1_000_000));
+        baseline.insert(5, "big.v2", Measure::Part(Part::Two), stats(9_000_000));
+        baseline.insert(5, "stdin-0123]#", Measure::Parse, stats(5_000));
+
+        assert_eq!(Baseline::parse(&baseline.to_string()).unwrap(), baseline);
+        assert!(baseline.to_string().contains("[day05.\"big.v2\".part2]\n"));
+        assert!(baseline
+            .to_string()
+            .contains("[day05.\"stdin-0123]#\".parse]\n"));
+    }
+
+    #[test]
+    fn baselines_are_kept_apart_by_input() {
+        let mut baseline = Baseline::default();
+        baseline.insert(5, "input", Measure::Parse, stats(36_300));
+
+        assert_eq!(
+            baseline.get(5, "input", Measure::Parse),
+            Some(&stats(36_300))
+        );
+        assert_eq!(baseline.get(5, "big", Measure::Parse), None);
+    }
+
+    #[test]
+    fn flags_changes_beyond_the_threshold() {
+        let baseline = stats(1000);
+
+        assert!(Verdict::compare(&stats(1200), &baseline, 0.1).is_regression());
+        assert!(matches!(
+            Verdict::compare(&stats(1050), &baseline, 0.1),
+            Verdict::Unchanged(_)
+        ));
+        assert!(matches!(
+            Verdict::compare(&stats(800), &baseline, 0.1),
+            Verdict::Improved(_)
+        ));
+    }
+}
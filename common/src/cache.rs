@@ -0,0 +1,326 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::http::{Curl, HttpClient, HttpError, Tcp};
+
+pub const SITE: &str = "https://adventofcode.com";
+
+// Asked for by the site, so that it knows who to contact about misbehaving scripts
+const USER_AGENT: &str = "github.com/datael/advent-of-code";
+
+// Held while fetching, so that threads wait their turn like separate runs do
+static FETCHING: Mutex<()> = Mutex::new(());
+
+// Puzzle inputs, downloaded once and kept in `dir` as `YEAR/dayNN.txt`. Downloads are
+// at least `min_interval` apart, even across runs, going by the time of the last one
+// recorded in the directory
+pub struct InputCache {
+    pub dir: PathBuf,
+    pub base_url: String,
+    // The `session` cookie of someone logged in to the site, as inputs differ per user
+    pub session: Option<String>,
+    pub client: Box<dyn HttpClient>,
+    pub min_interval: Duration,
+}
+
+#[derive(Debug)]
+pub enum CacheError {
+    NoSession,
+    Http(String, HttpError),
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    Io(PathBuf, io::Error),
+}
+
+impl Display for CacheError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => write!(
+                f,
+                "the input isn't cached and there is no session to download it with: set \
+                 AOC_SESSION, or put the session cookie in the cache directory's `session` file"
+            ),
+            Self::Http(url, error) => write!(f, "could not download `{url}`: {error}"),
+            Self::Status { url, status, body } => {
+                let reason = body.lines().next().unwrap_or_default();
+                write!(f, "could not download `{url}`: status {status}: {reason}")
+            }
+            Self::Io(path, error) => write!(f, "`{}`: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+impl InputCache {
+    // Configured by environment variables, all optional:
+    //   AOC_CACHE_DIR  where to keep inputs [default: ~/.cache/aoc]
+    //   AOC_SESSION    session cookie [default: the cache directory's `session` file]
+    //   AOC_BASE_URL   site to download from, e.g. a local mirror [default: SITE]
+    pub fn from_env() -> Self {
+        let dir = env::var_os("AOC_CACHE_DIR").map_or_else(default_dir, PathBuf::from);
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| SITE.to_string());
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(dir.join("session")).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+
+        Self {
+            client: client_for(&base_url),
+            dir,
+            base_url,
+            session,
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    // The cached input, downloading it first if it isn't there yet
+    pub fn input(&self, year: u16, day: u8) -> Result<String, CacheError> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() != io::ErrorKind::NotFound => {
+                return Err(CacheError::Io(path, error))
+            }
+            Err(_) => {}
+        }
+
+        let session = self.session.as_deref().ok_or(CacheError::NoSession)?;
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self.fetch(&url, session)?;
+
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| fs::write(&path, &input))
+            .map_err(|error| CacheError::Io(path, error))?;
+
+        Ok(input)
+    }
+
+    fn fetch(&self, url: &str, session: &str) -> Result<String, CacheError> {
+        let _turn = FETCHING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let stamp = self.dir.join(".last-fetch");
+
+        if let Some(wait) = last_fetch(&stamp)
+            .and_then(|last| SystemTime::now().duration_since(last).ok())
+            .and_then(|since| self.min_interval.checked_sub(since))
+        {
+            thread::sleep(wait);
+        }
+
+        // Stamped when the request starts, so that however long it takes, the next one
+        // starts at least `min_interval` after this one did. Failed attempts count too,
+        // or a bad session would be retried as fast as the runs come
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        let cookie = format!("session={session}");
+        let response = self
+            .client
+            .get(url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)]);
+
+        fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&stamp, nanos.to_string()))
+            .map_err(|error| CacheError::Io(stamp, error))?;
+
+        let response = response.map_err(|error| CacheError::Http(url.to_string(), error))?;
+
+        if response.status != 200 {
+            return Err(CacheError::Status {
+                url: url.to_string(),
+                status: response.status,
+                body: response.body,
+            });
+        }
+
+        Ok(response.body)
+    }
+}
+
+// Which puzzle a bundled input belongs to, going by where days live in the workspace,
+// e.g. `2023/day07/input.txt`
+pub fn puzzle(bundled: &Path) -> Option<(u16, u8)> {
+    let day_dir = bundled.parent()?;
+    let day = day_dir.file_name()?.to_str()?.strip_prefix("day")?;
+    let year = day_dir.parent()?.file_name()?.to_str()?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn default_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(|| PathBuf::from(".cache"))
+        .join("aoc")
+}
+
+// `Tcp` can't do HTTPS, but is all a plain HTTP mirror needs
+fn client_for(base_url: &str) -> Box<dyn HttpClient> {
+    if base_url.starts_with("http://") {
+        Box::new(Tcp::default())
+    } else {
+        Box::new(Curl)
+    }
+}
+
+fn last_fetch(stamp: &Path) -> Option<SystemTime> {
+    let nanos = fs::read_to_string(stamp).ok()?.trim().parse().ok()?;
+
+    Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    use super::*;
+
+    // Stands in for the site: answers `/2023/day/N/input` with `input N` for anyone
+    // logged in and 400 for anyone else, and 404 for any other path. Keeps the request
+    // lines it was sent
+    fn serve() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut lines = BufReader::new(&stream).lines().map(Result::unwrap);
+                let request = lines.next().unwrap();
+                let logged_in = lines
+                    .take_while(|line| !line.is_empty())
+                    .any(|line| line == "Cookie: session=secret");
+
+                let day = request
+                    .strip_prefix("GET /2023/day/")
+                    .and_then(|rest| rest.strip_suffix("/input HTTP/1.0"));
+                let response = match day {
+                    Some(day) if logged_in => format!("HTTP/1.1 200 OK\r\n\r\ninput {day}\n"),
+                    Some(_) => "HTTP/1.1 400 Bad Request\r\n\r\nPlease log in.\n".to_string(),
+                    None => "HTTP/1.1 404 Not Found\r\n\r\nNot found\n".to_string(),
+                };
+
+                seen.lock().unwrap().push(request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn cache(name: &str, base_url: String, session: Option<&str>) -> InputCache {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .with_file_name("target")
+            .join("input-cache")
+            .join(name);
+        let _ = fs::remove_dir_all(&dir);
+
+        InputCache {
+            dir,
+            base_url,
+            session: session.map(str::to_string),
+            client: Box::new(Tcp::default()),
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn downloads_inputs_once() {
+        let (base_url, requests) = serve();
+        let cache = cache("once", base_url, Some("secret"));
+
+        assert_eq!(cache.input(2023, 7).unwrap(), "input 7\n");
+        assert_eq!(cache.input(2023, 7).unwrap(), "input 7\n");
+        assert_eq!(
+            fs::read_to_string(cache.dir.join("2023/day07.txt")).unwrap(),
+            "input 7\n"
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /2023/day/7/input HTTP/1.0"]
+        );
+    }
+
+    #[test]
+    fn reports_failed_downloads() {
+        let (base_url, requests) = serve();
+
+        let cache = cache("failed", base_url, None);
+        assert!(matches!(cache.input(2023, 1), Err(CacheError::NoSession)));
+        assert!(requests.lock().unwrap().is_empty());
+
+        let cache = InputCache {
+            session: Some("wrong".to_string()),
+            ..cache
+        };
+        let error = cache.input(2023, 1).unwrap_err();
+        assert!(matches!(error, CacheError::Status { status: 400, .. }));
+        assert!(error.to_string().ends_with("status 400: Please log in."));
+        assert!(!cache.path(2023, 1).exists());
+
+        let cache = InputCache {
+            session: Some("secret".to_string()),
+            ..cache
+        };
+        assert!(matches!(
+            cache.input(2022, 1),
+            Err(CacheError::Status { status: 404, .. })
+        ));
+    }
+
+    #[test]
+    fn spaces_out_downloads() {
+        let (base_url, requests) = serve();
+        let cache = InputCache {
+            min_interval: Duration::from_millis(300),
+            ..cache("spaced", base_url, Some("secret"))
+        };
+
+        let start = Instant::now();
+
+        for day in 1..=3 {
+            cache.input(2023, day).unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(600));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn finds_the_puzzle_for_a_bundled_input() {
+        assert_eq!(
+            puzzle(Path::new("/aoc/2023/day07/input.txt")),
+            Some((2023, 7))
+        );
+        assert_eq!(puzzle(Path::new("day07/input.txt")), None);
+    }
+}
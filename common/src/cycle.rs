@@ -0,0 +1,227 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::number::{self, Cycle};
+
+// A simulation that, after its first `prefix` steps, repeats itself every `period` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Loop {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Loop {
+    // The first step after which the state is the same as it is after `steps` steps
+    pub fn earliest_equivalent(&self, steps: usize) -> usize {
+        if steps < self.prefix {
+            steps
+        } else {
+            self.prefix + (steps - self.prefix) % self.period
+        }
+    }
+}
+
+// Steps `state` until it is back in a state it has been in before, and leaves it there
+pub fn find_loop<S: Hash>(state: &mut S, step: impl FnMut(&mut S)) -> Loop {
+    find_loop_by(state, step, fingerprint)
+}
+
+// As `find_loop`, but only the part of the state picked out by `key` is compared, for
+// when the rest of it never repeats or doesn't matter
+pub fn find_loop_by_key<S, K: Hash>(
+    state: &mut S,
+    step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+) -> Loop {
+    find_loop_by(state, step, |state| fingerprint(&key(state)))
+}
+
+// The state after `steps` steps, skipping round any loop rather than simulating it
+pub fn state_after<S: Hash>(mut state: S, steps: usize, mut step: impl FnMut(&mut S)) -> S {
+    let mut seen = HashMap::new();
+
+    for taken in 0..steps {
+        if let Some(prefix) = see(&mut seen, fingerprint(&state), taken) {
+            crate::trace!("loop", prefix = prefix, period = taken - prefix);
+
+            for _ in 0..(steps - taken) % (taken - prefix) {
+                step(&mut state);
+            }
+
+            return state;
+        }
+
+        step(&mut state);
+    }
+
+    state
+}
+
+// The steps during which something happens in a looping simulation: `steps` holds
+// each of them up to the end of the first time round the loop, and the ones within the
+// loop happen again every time round it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub looping: Loop,
+    pub steps: Vec<usize>,
+}
+
+impl Schedule {
+    pub fn happens_at(&self, steps: usize) -> bool {
+        self.steps
+            .contains(&self.looping.earliest_equivalent(steps))
+    }
+}
+
+// The first step during which everything scheduled happens at once, if ever
+pub fn first_common_step(schedules: &[Schedule]) -> Option<usize> {
+    // Anything before a loop has started has to be checked by hand
+    let before_loops = schedules
+        .iter()
+        .flat_map(|schedule| {
+            schedule
+                .steps
+                .iter()
+                .filter(|steps| **steps < schedule.looping.prefix)
+        })
+        .copied()
+        .filter(|steps| schedules.iter().all(|schedule| schedule.happens_at(*steps)))
+        .min();
+
+    // After that, each happens at one of its steps within the loop every time round, so
+    // try every combination of those
+    let combinations = schedules
+        .iter()
+        .fold(vec![Vec::new()], |combinations, schedule| {
+            let period = schedule.looping.period as u64;
+
+            combinations
+                .iter()
+                .flat_map(|cycles| {
+                    schedule
+                        .steps
+                        .iter()
+                        .filter(|steps| **steps >= schedule.looping.prefix)
+                        .map(|steps| {
+                            let mut cycles = cycles.clone();
+                            cycles.push(Cycle::new(*steps as u64, period));
+                            cycles
+                        })
+                })
+                .collect::<Vec<_>>()
+        });
+
+    let in_loops = combinations
+        .iter()
+        .filter_map(|cycles| number::first_common_time(cycles))
+        .min()
+        .and_then(|steps| usize::try_from(steps).ok());
+
+    before_loops.into_iter().chain(in_loops).min()
+}
+
+// States are remembered by a hash rather than kept, so that big states are cheap to
+// track. A collision would be mistaken for a loop, but with 64 bits that won't happen
+fn fingerprint(state: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Returns when the state was first seen, if it has been seen before
+fn see(seen: &mut HashMap<u64, usize>, fingerprint: u64, steps: usize) -> Option<usize> {
+    match seen.entry(fingerprint) {
+        Entry::Occupied(entry) => Some(*entry.get()),
+        Entry::Vacant(entry) => {
+            entry.insert(steps);
+            None
+        }
+    }
+}
+
+fn find_loop_by<S>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    fingerprint: impl Fn(&S) -> u64,
+) -> Loop {
+    let mut seen = HashMap::new();
+
+    for steps in 0.. {
+        if let Some(prefix) = see(&mut seen, fingerprint(state), steps) {
+            crate::trace!("loop", prefix = prefix, period = steps - prefix);
+
+            return Loop {
+                prefix,
+                period: steps - prefix,
+            };
+        }
+
+        step(state);
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, ... until 7, then back round to 3: a prefix of 3 and a period of 5
+    fn step(n: &mut u32) {
+        *n = if *n == 7 { 3 } else { *n + 1 };
+    }
+
+    #[test]
+    fn finds_the_loop_and_skips_round_it() {
+        let mut state = 0;
+        let looping = find_loop(&mut state, step);
+
+        assert_eq!(
+            looping,
+            Loop {
+                prefix: 3,
+                period: 5
+            }
+        );
+        assert_eq!(state, 3);
+        assert_eq!(looping.earliest_equivalent(1_000_000_000), 5);
+
+        assert_eq!(state_after(0, 2, step), 2);
+        assert_eq!(state_after(0, 1_000_000_001, step), 6);
+    }
+
+    #[test]
+    fn compares_only_the_key() {
+        // The step count never repeats, but the value does
+        let mut state = (0, 0);
+        let looping = find_loop_by_key(
+            &mut state,
+            |(n, steps)| {
+                step(n);
+                *steps += 1;
+            },
+            |(n, _)| *n,
+        );
+
+        assert_eq!(looping.period, 5);
+        assert_eq!(state, (3, 8));
+    }
+
+    #[test]
+    fn schedules_meet() {
+        let schedule = |prefix, period, steps: &[usize]| Schedule {
+            looping: Loop { prefix, period },
+            steps: steps.to_vec(),
+        };
+
+        // Once early on, then every 4 steps from 6; and every 3 steps from 2
+        let schedules = [schedule(5, 4, &[1, 6]), schedule(0, 3, &[2])];
+        assert_eq!(first_common_step(&schedules), Some(14));
+
+        // Both happen at step 1 before either loop has started
+        let schedules = [schedule(5, 4, &[1, 6]), schedule(2, 1, &[1, 2])];
+        assert_eq!(first_common_step(&schedules), Some(1));
+    }
+}
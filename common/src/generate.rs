@@ -0,0 +1,274 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    geometry::{Direction, Position},
+    parse,
+    solution::{Part, Solution},
+};
+
+// A day that can make up puzzle inputs of its own, for stress testing and for
+// benchmarking at sizes beyond the real input. Generated inputs keep to whatever
+// the real ones guarantee and the solution relies on, e.g. that a loop closes or
+// that there is always a way through
+pub trait Generate: Solution {
+    // Roughly the size of the real input
+    const DEFAULT_SIZE: usize;
+
+    // The range of sizes there are inputs of
+    const MIN_SIZE: usize = 1;
+    const MAX_SIZE: usize = usize::MAX;
+
+    // Why there's no input of the given size, for days with more to say than the range
+    fn check_size(size: usize) -> Result<(), String> {
+        if size < Self::MIN_SIZE {
+            Err(format!("the size must be at least {}", Self::MIN_SIZE))
+        } else if size > Self::MAX_SIZE {
+            Err(format!("the size must be at most {}", Self::MAX_SIZE))
+        } else {
+            Ok(())
+        }
+    }
+
+    // `size` is a rough scale, such as the side of a grid or the number of lines;
+    // each day says what it means for its input. It has always passed `check_size`
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+}
+
+// The same seed and size always give the same input. Panics if there's no input of
+// that size
+pub fn generate<G: Generate>(seed: u64, size: usize) -> String {
+    if let Err(error) = G::check_size(size) {
+        panic!("day {} size {size}: {error}", G::DAY);
+    }
+
+    G::generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+// Solves a handful of generated inputs of the given size, and of the smallest size there
+// is, failing on any that don't parse. Panics in the solution itself are failures too
+pub fn assert_generated_inputs_solve<G: Generate>(size: usize) {
+    for (seed, size) in (0..5).flat_map(|seed| [(seed, G::MIN_SIZE), (seed, size)]) {
+        let input = generate::<G>(seed, size);
+        let input = parse::normalise(&input);
+
+        let parsed = G::parse(&input).unwrap_or_else(|error| {
+            panic!(
+                "day {} seed {seed} size {size}\n{}",
+                G::DAY,
+                error.render("generated", &input)
+            )
+        });
+
+        for part in Part::ALL {
+            G::part(&parsed, part);
+        }
+    }
+}
+
+// Solves `input` cut off after each of its lines in turn, failing if any of them panics.
+// Being cut off is no excuse for a crash: a short input should be reported when it's
+// parsed, or give no answer
+pub fn assert_truncated_inputs_solve<S: Solution>(input: &str) {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    for end in 1..lines.len() {
+        let truncated = lines[..end].join("\n");
+        let truncated = parse::normalise(&truncated);
+
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            if let Ok(parsed) = S::parse(&truncated) {
+                for part in Part::ALL {
+                    S::part(&parsed, part);
+                }
+            }
+        }));
+
+        assert!(
+            solved.is_ok(),
+            "day {} panicked on the first {end} lines of its input",
+            S::DAY
+        );
+    }
+}
+
+// A grid of `width` by `height` characters, each chosen by `cell` given its x and y
+pub fn grid(
+    rng: &mut StdRng,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut StdRng, usize, usize) -> char,
+) -> String {
+    let mut text = String::with_capacity((width + 1) * height);
+
+    for y in 0..height {
+        text.extend((0..width).map(|x| cell(rng, x, y)));
+        text.push('\n');
+    }
+
+    text
+}
+
+// One of `choices`, picked in proportion to their weights
+pub fn weighted<T: Copy>(rng: &mut StdRng, choices: &[(T, u32)]) -> T {
+    choices
+        .choose_weighted(rng, |(_, weight)| *weight)
+        .expect("there must be a choice with a positive weight")
+        .0
+}
+
+// `count` different names of `len` lowercase letters each
+pub fn names(rng: &mut StdRng, count: usize, len: u32) -> Vec<String> {
+    assert!(
+        (count as u64) <= 26u64.pow(len),
+        "there aren't {count} names of {len} letters"
+    );
+
+    let mut names = Vec::with_capacity(count);
+
+    while names.len() < count {
+        let name = (0..len)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+// A random loop within a `size` by `size` grid of tiles, at least 2 wide, that never
+// crosses or touches itself: where it starts, and each step round it back to there. It
+// goes round the outline of a shape with no holes, made of columns of squares that
+// each overlap the next
+pub fn outline(rng: &mut StdRng, size: usize) -> (Position, Vec<Direction>) {
+    assert!(size >= 2, "a loop needs at least 2 by 2 tiles");
+
+    // The loop runs between tiles' centres, so the shape is made of the squares
+    // between them, of which there are one fewer each way
+    let squares = size - 1;
+
+    // Spanning most of the width, so that the size says roughly how long the loop is
+    let left = rng.gen_range(0..=squares / 4);
+    let right = rng.gen_range(left.max(squares - 1 - squares / 4)..squares);
+
+    let mut columns = Vec::new();
+    let mut top = rng.gen_range(0..squares);
+    let mut bottom = rng.gen_range(top..squares);
+
+    for _ in left..=right {
+        columns.push((top, bottom));
+
+        // Overlapping the previous column keeps the shape in one piece, without any
+        // corners that only touch diagonally
+        let next_top = rng.gen_range(top.saturating_sub(3)..=bottom.min(top + 3));
+        let lowest = top.max(next_top).max(bottom.saturating_sub(3));
+        let next_bottom = rng.gen_range(lowest..=(bottom + 3).min(squares - 1));
+        (top, bottom) = (next_top, next_bottom);
+    }
+
+    let inside = |x: i64, y: i64| {
+        x >= left as i64
+            && y >= 0
+            && columns
+                .get((x - left as i64) as usize)
+                .is_some_and(|&(top, bottom)| y >= top as i64 && y <= bottom as i64)
+    };
+
+    // A tile connects along each of its edges that has the shape on one side only
+    let connects = |position: Position, direction: Direction| {
+        let (x, y) = (position.x, position.y);
+
+        match direction {
+            Direction::Up => inside(x - 1, y - 1) != inside(x, y - 1),
+            Direction::Down => inside(x - 1, y) != inside(x, y),
+            Direction::Left => inside(x - 1, y - 1) != inside(x - 1, y),
+            Direction::Right => inside(x, y - 1) != inside(x, y),
+        }
+    };
+
+    // The top left corner of the leftmost column is always on the loop, heading right
+    let start = Position::new(left as i64, columns[0].0 as i64);
+    let mut steps = vec![Direction::Right];
+    let mut position = start + Direction::Right.as_offset();
+
+    while position != start {
+        let from = steps[steps.len() - 1].opposite();
+        let direction = Direction::ALL
+            .into_iter()
+            .find(|direction| *direction != from && connects(position, *direction))
+            .unwrap();
+
+        steps.push(direction);
+        position += direction.as_offset();
+    }
+
+    // Start somewhere else round the loop
+    let skip = rng.gen_range(0..steps.len());
+    let start = steps[..skip].iter().fold(start, |position, direction| {
+        position + direction.as_offset()
+    });
+    steps.rotate_left(skip);
+
+    (start, steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers_are_repeatable() {
+        let make = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            (
+                grid(&mut rng, 3, 2, |rng, x, _| {
+                    if x == 0 {
+                        '|'
+                    } else {
+                        weighted(rng, &[('.', 3), ('#', 1)])
+                    }
+                }),
+                names(&mut rng, 5, 2),
+            )
+        };
+
+        let (grid, names) = make(1);
+
+        assert_eq!(make(1), (grid.clone(), names.clone()));
+        assert_eq!(grid.lines().map(str::len).collect::<Vec<_>>(), [3, 3]);
+        assert!(grid.lines().all(|line| line.starts_with('|')));
+        assert_eq!(names.len(), 5);
+        assert!(names.iter().all(|name| name.len() == 2));
+    }
+
+    #[test]
+    fn outlines_are_closed_loops() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for size in [2, 3, 10, 50] {
+            let (start, steps) = outline(&mut rng, size);
+
+            let positions = steps
+                .iter()
+                .scan(start, |position, direction| {
+                    *position += direction.as_offset();
+                    Some(*position)
+                })
+                .collect::<Vec<_>>();
+
+            let unique = positions.iter().collect::<std::collections::HashSet<_>>();
+
+            assert_eq!(positions.last(), Some(&start));
+            assert_eq!(unique.len(), positions.len());
+            assert!(positions
+                .iter()
+                .all(|position| (0..size as i64).contains(&position.x)
+                    && (0..size as i64).contains(&position.y)));
+        }
+    }
+}
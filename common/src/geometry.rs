@@ -0,0 +1,343 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Offset {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Position {
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    // Orthogonal neighbours, in the order of `Direction::ALL`
+    pub fn neighbours(&self) -> impl Iterator<Item = Position> {
+        let position = *self;
+
+        Direction::ALL
+            .iter()
+            .map(move |direction| position + direction.as_offset())
+    }
+
+    // Orthogonal and diagonal neighbours
+    pub fn neighbours_with_diagonals(&self) -> impl Iterator<Item = Position> {
+        let position = *self;
+
+        Offset::SURROUNDING
+            .iter()
+            .map(move |offset| position + *offset)
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Offset {
+    pub const SURROUNDING: [Offset; 8] = [
+        Offset { x: -1, y: -1 },
+        Offset { x: 0, y: -1 },
+        Offset { x: 1, y: -1 },
+        Offset { x: -1, y: 0 },
+        Offset { x: 1, y: 0 },
+        Offset { x: -1, y: 1 },
+        Offset { x: 0, y: 1 },
+        Offset { x: 1, y: 1 },
+    ];
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self {
+            x: x as i64,
+            y: y as i64,
+        }
+    }
+}
+
+impl Add<Offset> for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Offset) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl AddAssign<Offset> for Position {
+    fn add_assign(&mut self, rhs: Offset) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Offset> for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Offset) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl SubAssign<Offset> for Position {
+    fn sub_assign(&mut self, rhs: Offset) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Offset {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Mul<i64> for Offset {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+// y grows downwards, matching the way the puzzle inputs are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    pub fn as_offset(&self) -> Offset {
+        match self {
+            Self::Up => Offset { x: 0, y: -1 },
+            Self::Down => Offset { x: 0, y: 1 },
+            Self::Left => Offset { x: -1, y: 0 },
+            Self::Right => Offset { x: 1, y: 0 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Offset3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Position3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    // Not `max`, since that would be shadowed by (or shadow) `Ord::max`
+    pub fn component_max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    pub fn component_min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+}
+
+impl Offset3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Add<Offset3> for Position3 {
+    type Output = Self;
+
+    fn add(self, rhs: Offset3) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl AddAssign<Offset3> for Position3 {
+    fn add_assign(&mut self, rhs: Offset3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Offset3> for Position3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Offset3) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Sub for Position3 {
+    type Output = Offset3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Offset3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Mul<i64> for Offset3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_go_past_the_origin() {
+        let neighbours = Position::ORIGIN.neighbours().collect::<Vec<_>>();
+
+        assert_eq!(
+            neighbours,
+            [
+                Position::new(0, -1),
+                Position::new(0, 1),
+                Position::new(-1, 0),
+                Position::new(1, 0)
+            ]
+        );
+
+        let around = Position::new(-5, 7)
+            .neighbours_with_diagonals()
+            .collect::<Vec<_>>();
+
+        assert_eq!(around.len(), 8);
+        assert!(around
+            .iter()
+            .all(|position| position.manhattan_distance(&Position::new(-5, 7)) <= 2));
+        assert!(!around.contains(&Position::new(-5, 7)));
+    }
+
+    #[test]
+    fn directions_turn_and_reverse() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_eq!(-direction.as_offset(), direction.opposite().as_offset());
+            assert_ne!(direction.is_vertical(), direction.turn_left().is_vertical());
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(
+            Position::new(1, 1) + Direction::Up.as_offset() * 3,
+            Position::new(1, -2)
+        );
+    }
+}
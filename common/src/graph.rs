@@ -0,0 +1,360 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+// Graphs are never built up front: a graph is anything that can say where you
+// can go from a node. Closures work, as do adjacency maps
+pub trait Neighbours<N> {
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N>;
+}
+
+// As `Neighbours`, but each step has a cost
+pub trait Successors<N, C> {
+    fn successors(&self, node: &N) -> impl IntoIterator<Item = (N, C)>;
+}
+
+impl<N, I, F> Neighbours<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self(node)
+    }
+}
+
+impl<N, C, I, F> Successors<N, C> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    fn successors(&self, node: &N) -> impl IntoIterator<Item = (N, C)> {
+        self(node)
+    }
+}
+
+// Nodes missing from the map have no neighbours
+impl<N: Clone + Eq + Hash> Neighbours<N> for HashMap<N, Vec<N>> {
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Successors<N, C> for HashMap<N, HashMap<N, C>> {
+    fn successors(&self, node: &N) -> impl IntoIterator<Item = (N, C)> {
+        self.get(node)
+            .into_iter()
+            .flatten()
+            .map(|(next, cost)| (next.clone(), *cost))
+    }
+}
+
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($type:ty),*) => {
+        $(
+            impl Cost for $type {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// The nodes run from the start to the goal, inclusive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+// How many steps it takes to reach every node reachable from any of `starts`,
+// going no further than `max_depth` steps if given
+pub fn bfs_distances<N: Clone + Eq + Hash>(
+    starts: impl IntoIterator<Item = N>,
+    graph: &impl Neighbours<N>,
+    max_depth: Option<usize>,
+) -> HashMap<N, usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+
+        if max_depth.is_some_and(|max_depth| distance >= max_depth) {
+            continue;
+        }
+
+        for next in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(distance + 1);
+            }
+        }
+    }
+
+    distances
+}
+
+// The path with the fewest steps from `start` to the first node found that satisfies `is_goal`
+pub fn bfs<N: Clone + Eq + Hash>(
+    start: N,
+    graph: &impl Neighbours<N>,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<Vec<N>> {
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let node = search.nodes[index].clone();
+
+        if is_goal(&node) {
+            return Some(search.path_to(index));
+        }
+
+        for next in graph.neighbours(&node) {
+            if let Some(next_index) = search.discover(next, index) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<N: Clone + Eq + Hash, C: Cost>(
+    start: N,
+    graph: &impl Successors<N, C>,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<Path<N, C>> {
+    astar(start, graph, |_| C::ZERO, is_goal)
+}
+
+// `heuristic` must never overestimate the cost left to reach a goal, or the
+// path found may not be the cheapest
+pub fn astar<N: Clone + Eq + Hash, C: Cost>(
+    start: N,
+    graph: &impl Successors<N, C>,
+    heuristic: impl Fn(&N) -> C,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<Path<N, C>> {
+    let mut search = Search::new(start);
+
+    // The cheapest known cost to reach each node in the search
+    let mut costs = vec![C::ZERO];
+
+    // Reverse because we want heap to act as a min-heap
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&search.nodes[0]), C::ZERO, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // A cheaper way here has been found since this was queued
+        if cost > costs[index] {
+            continue;
+        }
+
+        let node = search.nodes[index].clone();
+
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: search.path_to(index),
+            });
+        }
+
+        for (next, step_cost) in graph.successors(&node) {
+            let next_cost = cost + step_cost;
+
+            let next_index = match search.index_of(&next) {
+                Some(next_index) if next_cost < costs[next_index] => {
+                    search.previous[next_index] = Some(index);
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+                Some(_) => continue,
+                None => {
+                    costs.push(next_cost);
+                    search.discover(next, index).unwrap()
+                }
+            };
+
+            let estimate = next_cost + heuristic(&search.nodes[next_index]);
+            heap.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+// Assumes that the graph is undirected, i.e. that every edge has a matching one going back
+pub fn connected_components<N: Clone + Eq + Hash>(
+    nodes: impl IntoIterator<Item = N>,
+    graph: &impl Neighbours<N>,
+) -> Vec<HashSet<N>> {
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+
+        let component = bfs_distances([node], graph, None)
+            .into_keys()
+            .collect::<HashSet<_>>();
+
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
+
+// Nodes are numbered as they are discovered so that the heaps and queues
+// needn't hold (or be able to order) the nodes themselves
+struct Search<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    // Which node each node was reached from, for rebuilding paths
+    previous: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            previous: vec![None],
+        }
+    }
+
+    fn index_of(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    // Returns the new node's index, or `None` if it has been seen before
+    fn discover(&mut self, node: N, from: usize) -> Option<usize> {
+        let Entry::Vacant(entry) = self.indices.entry(node) else {
+            return None;
+        };
+
+        let index = self.nodes.len();
+        self.nodes.push(entry.key().clone());
+        self.previous.push(Some(from));
+        entry.insert(index);
+
+        Some(index)
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+
+        while let Some(previous) = self.previous[index] {
+            path.push(self.nodes[previous].clone());
+            index = previous;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2 - 3, with a costly shortcut from 0 to 3, and 4 - 5 on their own
+    fn weighted() -> HashMap<u8, HashMap<u8, u32>> {
+        HashMap::from([
+            (0, HashMap::from([(1, 1), (3, 10)])),
+            (1, HashMap::from([(0, 1), (2, 2)])),
+            (2, HashMap::from([(1, 2), (3, 3)])),
+            (3, HashMap::from([(2, 3), (0, 10)])),
+            (4, HashMap::from([(5, 1)])),
+            (5, HashMap::from([(4, 1)])),
+        ])
+    }
+
+    fn unweighted() -> HashMap<u8, Vec<u8>> {
+        weighted()
+            .into_iter()
+            .map(|(node, nexts)| (node, nexts.into_keys().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_measures_distances_up_to_a_depth() {
+        let graph = unweighted();
+
+        assert_eq!(
+            bfs_distances([0], &graph, None),
+            HashMap::from([(0, 0), (1, 1), (2, 2), (3, 1)])
+        );
+        assert_eq!(
+            bfs_distances([1, 4], &graph, Some(1)),
+            HashMap::from([(0, 1), (1, 0), (2, 1), (4, 0), (5, 1)])
+        );
+        assert_eq!(
+            bfs(1, &graph, |node| *node == 3).map(|path| path.len()),
+            Some(3)
+        );
+        assert_eq!(bfs(4, &graph, |node| *node == 5), Some(vec![4, 5]));
+        assert_eq!(bfs(0, &graph, |node| *node == 4), None);
+    }
+
+    #[test]
+    fn finds_the_cheapest_path() {
+        let expected = Some(Path {
+            cost: 6,
+            nodes: vec![0, 1, 2, 3],
+        });
+
+        assert_eq!(dijkstra(0, &weighted(), |node| *node == 3), expected);
+        assert_eq!(
+            astar(
+                0,
+                &weighted(),
+                |node| 3 - u32::from(*node),
+                |node| *node == 3
+            ),
+            expected
+        );
+        assert_eq!(dijkstra(0, &weighted(), |node| *node == 5), None);
+    }
+
+    #[test]
+    fn works_with_closures() {
+        // A number line where you can add 3 for a cost of 1, or subtract 1 for a cost of 2
+        let line = |n: &i32| [(n + 3, 1), (n - 1, 2)];
+
+        let path = dijkstra(0, &line, |n| *n == 5).unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&5));
+    }
+
+    #[test]
+    fn splits_into_connected_components() {
+        let mut components = connected_components(0..6, &unweighted());
+        components.sort_by_key(HashSet::len);
+
+        assert_eq!(
+            components,
+            [HashSet::from([4, 5]), HashSet::from([0, 1, 2, 3])]
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::Position,
+    parse::{self, ParseError},
+};
+
+// Anything that can be looked up by position; lets the same algorithm run over
+// a bounded grid or an infinitely repeating view of one
+pub trait GridView<T> {
+    fn get(&self, position: &Position) -> Option<&T>;
+
+    fn is_within_bounds(&self, position: &Position) -> bool {
+        self.get(position).is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid dimensions do not match");
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows are not all the same length"
+        );
+
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn parse(input: &str, parser: impl FnMut(char) -> T) -> Self {
+        let mut parser = parser;
+
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut parser).collect())
+                .collect(),
+        )
+    }
+
+    // As `parse`, but reports characters that `parser` rejects and rows of differing lengths.
+    // `expected` describes the characters that are accepted
+    pub fn try_parse(
+        input: &str,
+        expected: &str,
+        parser: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = parse::char_rows(parse::lines(input), expected, parser)?;
+
+        // Empty input still has a line, so has one row with nothing in it
+        if rows.first().is_none_or(Vec::is_empty) {
+            return Err(ParseError::end_of_input(input, "expected a grid"));
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_within_bounds(&self, position: &Position) -> bool {
+        position.x >= 0
+            && position.x < self.width as i64
+            && position.y >= 0
+            && position.y < self.height as i64
+    }
+
+    fn index_of(&self, position: &Position) -> Option<usize> {
+        if self.is_within_bounds(position) {
+            Some(position.y as usize * self.width + position.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, position: &Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: &Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    pub fn swap(&mut self, a: &Position, b: &Position) {
+        let a = self.index_of(a).expect("position out of bounds");
+        let b = self.index_of(b).expect("position out of bounds");
+
+        self.cells.swap(a, b);
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        let height = self.height;
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| Position::from((x, y))))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    // Orthogonal neighbours that are within the grid
+    pub fn neighbours(&self, position: &Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours()
+            .filter(|neighbour| self.is_within_bounds(neighbour))
+    }
+
+    // Orthogonal and diagonal neighbours that are within the grid
+    pub fn neighbours_with_diagonals(
+        &self,
+        position: &Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours_with_diagonals()
+            .filter(|neighbour| self.is_within_bounds(neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid { grid: self }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(&position).expect("position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(&position).expect("position out of bounds")
+    }
+}
+
+impl<T> GridView<T> for Grid<T> {
+    fn get(&self, position: &Position) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn is_within_bounds(&self, position: &Position) -> bool {
+        self.is_within_bounds(position)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Treats the underlying grid as repeating forever in every direction
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> WrappingGrid<'_, T> {
+    pub fn wrap(&self, position: &Position) -> Position {
+        Position {
+            x: position.x.rem_euclid(self.grid.width as i64),
+            y: position.y.rem_euclid(self.grid.height as i64),
+        }
+    }
+}
+
+impl<T> GridView<T> for WrappingGrid<'_, T> {
+    fn get(&self, position: &Position) -> Option<&T> {
+        self.grid.get(&self.wrap(position))
+    }
+
+    fn is_within_bounds(&self, _: &Position) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::try_parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn try_parse_reports_bad_grids() {
+        let grid = parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 6);
+
+        let ragged = parse_digits("123\n45\n789\n").unwrap_err();
+        assert_eq!((ragged.line, ragged.column), (2, 1));
+
+        let unknown = parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!(
+            (unknown.line, unknown.column, unknown.text.as_str()),
+            (2, 2, "x")
+        );
+
+        assert!(parse_digits("").is_err());
+    }
+
+    #[test]
+    fn get_is_none_out_of_bounds() {
+        let grid = parse_digits("12\n34\n").unwrap();
+
+        assert_eq!(grid.get(&Position::new(1, 1)), Some(&4));
+
+        for position in [
+            (-1, 0),
+            (0, -1),
+            (2, 0),
+            (0, 2),
+            (2, 2),
+            (i64::MIN, i64::MAX),
+        ] {
+            let position = Position::new(position.0, position.1);
+
+            assert_eq!(grid.get(&position), None);
+            assert!(!grid.is_within_bounds(&position));
+        }
+    }
+
+    #[test]
+    fn wrapping_grids_repeat_in_every_direction() {
+        let grid = parse_digits("123\n456\n").unwrap();
+        let wrapping = grid.wrapping();
+
+        assert_eq!(wrapping.wrap(&Position::new(-1, -1)), Position::new(2, 1));
+        assert_eq!(wrapping.wrap(&Position::new(-4, -3)), Position::new(2, 1));
+        assert_eq!(wrapping.wrap(&Position::new(3, 2)), Position::ORIGIN);
+        assert_eq!(wrapping.get(&Position::new(-3, 5)), Some(&4));
+        assert!(wrapping.is_within_bounds(&Position::new(-100, 100)));
+    }
+
+    #[test]
+    fn neighbours_stay_within_the_grid() {
+        let grid = Grid::filled(3, 3, ());
+        let count = |position| grid.neighbours(&position).count();
+        let count_with_diagonals = |position| grid.neighbours_with_diagonals(&position).count();
+
+        assert_eq!(count(Position::ORIGIN), 2);
+        assert_eq!(count(Position::new(1, 0)), 3);
+        assert_eq!(count(Position::new(1, 1)), 4);
+        assert_eq!(count_with_diagonals(Position::new(2, 2)), 3);
+        assert_eq!(count_with_diagonals(Position::new(0, 1)), 5);
+        assert_eq!(count_with_diagonals(Position::new(1, 1)), 8);
+
+        assert_eq!(
+            grid.neighbours(&Position::new(2, 0)).collect::<Vec<_>>(),
+            [Position::new(2, 1), Position::new(1, 0)]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+// Just enough HTTP to download puzzle inputs. The site is HTTPS only, which needs
+// `Curl`; `Tcp` speaks plain HTTP, for mirrors and for tests
+pub trait HttpClient: Send + Sync {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, HttpError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    UnsupportedUrl(String),
+    Io(io::Error),
+    Malformed(String),
+    Curl(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedUrl(url) => write!(f, "unsupported URL `{url}`"),
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Malformed(reason) => write!(f, "malformed response: {reason}"),
+            Self::Curl(message) => write!(f, "curl failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<io::Error> for HttpError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// Runs the `curl` command line tool. Headers are passed on stdin rather than as
+// arguments, so that the session token doesn't show up in the process list
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Curl;
+
+impl HttpClient for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, HttpError> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--location", "--header", "@-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut stdin = child.stdin.take().unwrap();
+
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+
+        drop(stdin);
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(HttpError::Curl(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let output = String::from_utf8(output.stdout)
+            .map_err(|_| HttpError::Malformed("the body is not UTF-8".to_string()))?;
+
+        // `--write-out` puts the status after the body, on a line of its own
+        let (body, status) = output
+            .rsplit_once('\n')
+            .ok_or_else(|| HttpError::Malformed("no status from curl".to_string()))?;
+
+        Ok(Response {
+            status: parse_status(status)?,
+            body: body.to_string(),
+        })
+    }
+}
+
+// HTTP/1.0 over a plain `TcpStream`, so that responses are never chunked and the
+// body is everything after the headers
+#[derive(Debug, Clone, Copy)]
+pub struct Tcp {
+    pub timeout: Duration,
+}
+
+impl Default for Tcp {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl HttpClient for Tcp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, HttpError> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| HttpError::UnsupportedUrl(url.to_string()))?;
+        let (host, path) = rest.find('/').map_or((rest, "/"), |at| rest.split_at(at));
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut stream = TcpStream::connect(address)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut request = format!("GET {path} HTTP/1.0\r\nHost: {host}\r\n");
+
+        for (name, value) in headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes())?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        parse_response(&response)
+    }
+}
+
+fn parse_response(response: &str) -> Result<Response, HttpError> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| HttpError::Malformed("no end to the headers".to_string()))?;
+
+    // e.g. `HTTP/1.1 404 Not Found`
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .ok_or_else(|| HttpError::Malformed("no status line".to_string()))?;
+
+    Ok(Response {
+        status: parse_status(status)?,
+        body: body.to_string(),
+    })
+}
+
+fn parse_status(status: &str) -> Result<u16, HttpError> {
+    status
+        .trim()
+        .parse()
+        .map_err(|_| HttpError::Malformed(format!("invalid status `{status}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    // Answers a single request by echoing its headers back as the body
+    fn serve_once() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/echo", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let headers = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .skip(1)
+                .take_while(|line| !line.is_empty())
+                .filter(|line| line.starts_with("X-"))
+                .collect::<Vec<_>>();
+
+            write!(
+                stream,
+                "HTTP/1.1 201 Created\r\n\r\n{}\n",
+                headers.join("\n")
+            )
+            .unwrap();
+        });
+
+        url
+    }
+
+    fn assert_echoes(client: &dyn HttpClient) {
+        let response = client
+            .get(&serve_once(), &[("X-One", "1"), ("X-Two", "a b")])
+            .unwrap();
+
+        assert_eq!(
+            response,
+            Response {
+                status: 201,
+                body: "X-One: 1\nX-Two: a b\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn tcp_sends_headers() {
+        assert_echoes(&Tcp::default());
+        assert!(matches!(
+            Tcp::default().get("https://example.com/", &[]),
+            Err(HttpError::UnsupportedUrl(_))
+        ));
+    }
+
+    #[test]
+    fn curl_sends_headers() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            return;
+        }
+
+        assert_echoes(&Curl);
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            parse_response("HTTP/1.1 404 Not Found\r\nServer: x\r\n\r\nmissing\r\n\r\n").unwrap(),
+            Response {
+                status: 404,
+                body: "missing\r\n\r\n".to_string(),
+            }
+        );
+        assert!(matches!(
+            parse_response("HTTP/1.1 200 OK\r\n"),
+            Err(HttpError::Malformed(_))
+        ));
+    }
+}
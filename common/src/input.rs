@@ -0,0 +1,393 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{
+    cache::{self, CacheError, InputCache},
+    parallel::available_threads,
+    parse,
+    solution::Part,
+    trace,
+};
+
+const USAGE: &str = "\
+Arguments:
+  [INPUT]  Path to the puzzle input, or `-` to read it from stdin.
+           Defaults to the bundled input.txt, or to the downloaded input
+           if there isn't one (see the README).
+
+Options:
+  -p, --part <1|2>  Only run the given part
+  --json            Print the answers and timings as JSON
+  --record          Save the answers to answers.toml as the known answers for INPUT
+  --trace[=<PATH>]  Log what the solution does along the way to stderr, or to PATH
+  --parallel[=<N>]  Share the work out between up to N threads where it can be
+                    [default: one per CPU]
+  -h, --help        Print this message";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Bundled,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    // How the input is referred to in diagnostics
+    pub fn name(&self) -> String {
+        match self {
+            Self::Bundled => "input.txt".to_string(),
+            Self::Stdin => "<stdin>".to_string(),
+            Self::Path(path) => path.display().to_string(),
+        }
+    }
+
+    // How the input is keyed in the answers file: `input` for the bundled input.txt
+    // (`bundled`), otherwise the file's name without its extension followed by a hash
+    // of `text`, so that files which only share a name don't share answers
+    pub fn answers_key(&self, bundled: &Path, text: &str) -> String {
+        let name = match self {
+            Self::Bundled => return "input".to_string(),
+            Self::Path(path) if same_file(path, bundled) => return "input".to_string(),
+            Self::Stdin => "stdin".to_string(),
+            Self::Path(path) => path
+                .file_stem()
+                .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()),
+        };
+
+        format!("{name}-{:016x}", fnv1a(parse::normalise(text).as_bytes()))
+    }
+
+    // `bundled` is the file to read for `InputSource::Bundled`. Without one, the input
+    // comes from the cache of downloaded inputs instead
+    pub fn read(&self, bundled: &Path) -> Result<String, InputError> {
+        match self {
+            Self::Bundled if !bundled.exists() => match cache::puzzle(bundled) {
+                Some((year, day)) => InputCache::from_env()
+                    .input(year, day)
+                    .map_err(InputError::Download),
+                None => read_file(bundled),
+            },
+            Self::Bundled => read_file(bundled),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            Self::Path(path) => read_file(path),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Read(PathBuf, io::Error),
+    Stdin(io::Error),
+    Download(CacheError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, error) => {
+                write!(f, "could not read input file `{}`: {error}", path.display())
+            }
+            Self::Stdin(error) => write!(f, "could not read input from stdin: {error}"),
+            Self::Download(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgsError {
+    UnexpectedArgument(String),
+    MissingValue(&'static str),
+    InvalidPart(String),
+    InvalidThreads(String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            Self::MissingValue(option) => write!(f, "`{option}` requires a value"),
+            Self::InvalidPart(part) => write!(f, "invalid part `{part}`: expected 1 or 2"),
+            Self::InvalidThreads(threads) => {
+                write!(f, "invalid thread count `{threads}`: expected at least 1")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+// Command line handling shared by every day's binary
+#[derive(Debug, Clone)]
+pub struct Args {
+    source: InputSource,
+    part: Option<Part>,
+    json: bool,
+    record: bool,
+    trace: Option<trace::Target>,
+    parallel: Option<usize>,
+    bundle_dir: PathBuf,
+}
+
+impl Args {
+    // `bundle_dir` is where the bundled input files live; pass `env!("CARGO_MANIFEST_DIR")`.
+    // Prints usage and exits if the arguments can't be understood.
+    pub fn from_env(bundle_dir: &str) -> Self {
+        let mut args = env::args();
+
+        let program = args
+            .next()
+            .as_deref()
+            .map(Path::new)
+            .and_then(Path::file_name)
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let usage =
+            format!("Usage: {program} [INPUT] [--part <1|2>] [--json] [--record] [--trace[=<PATH>]] [--parallel[=<N>]]\n\n{USAGE}");
+
+        match Self::parse(bundle_dir, args) {
+            Ok(Some(args)) => args,
+            Ok(None) => {
+                println!("{usage}");
+                process::exit(0);
+            }
+            Err(error) => {
+                eprintln!("error: {error}\n\n{usage}");
+                process::exit(2);
+            }
+        }
+    }
+
+    // Returns `None` if help was requested
+    pub fn parse(
+        bundle_dir: &str,
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Option<Self>, ArgsError> {
+        let mut source = InputSource::Bundled;
+        let mut part = None;
+        let mut json = false;
+        let mut record = false;
+        let mut trace = None;
+        let mut parallel = None;
+
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-p" | "--part" => {
+                    let value = args.next().ok_or(ArgsError::MissingValue("--part"))?;
+                    part = Some(parse_part(&value)?);
+                }
+                "--json" => json = true,
+                "--record" => record = true,
+                "--trace" => trace = Some(trace::Target::Stderr),
+                "--parallel" => parallel = Some(available_threads()),
+                "-" if source == InputSource::Bundled => source = InputSource::Stdin,
+                _ if arg.starts_with("--part=") => {
+                    part = Some(parse_part(&arg["--part=".len()..])?);
+                }
+                _ if arg.starts_with("--trace=") => {
+                    trace = Some(trace::Target::File(PathBuf::from(&arg["--trace=".len()..])));
+                }
+                _ if arg.starts_with("--parallel=") => {
+                    let value = &arg["--parallel=".len()..];
+                    parallel = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|threads| *threads > 0)
+                            .ok_or_else(|| ArgsError::InvalidThreads(value.to_string()))?,
+                    );
+                }
+                _ if !arg.starts_with('-') && source == InputSource::Bundled => {
+                    source = InputSource::Path(PathBuf::from(arg));
+                }
+                _ => return Err(ArgsError::UnexpectedArgument(arg)),
+            }
+        }
+
+        Ok(Some(Self {
+            source,
+            part,
+            json,
+            record,
+            trace,
+            parallel,
+            bundle_dir: PathBuf::from(bundle_dir),
+        }))
+    }
+
+    // As `parse`, for commands that only read an input, such as a day's own reports:
+    // any option given that isn't in `allowed` is an error, rather than quietly ignored
+    pub fn parse_only(
+        bundle_dir: &str,
+        args: impl IntoIterator<Item = String>,
+        allowed: &[&str],
+    ) -> Result<Option<Self>, ArgsError> {
+        let Some(args) = Self::parse(bundle_dir, args)? else {
+            return Ok(None);
+        };
+
+        let given = [
+            ("--part", args.part.is_some()),
+            ("--json", args.json),
+            ("--record", args.record),
+            ("--trace", args.trace.is_some()),
+            ("--parallel", args.parallel.is_some()),
+        ];
+
+        match given
+            .into_iter()
+            .find(|(option, given)| *given && !allowed.contains(option))
+        {
+            Some((option, _)) => Err(ArgsError::UnexpectedArgument(option.to_string())),
+            None => Ok(Some(args)),
+        }
+    }
+
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    pub fn part(&self) -> Option<Part> {
+        self.part
+    }
+
+    pub fn json(&self) -> bool {
+        self.json
+    }
+
+    pub fn record(&self) -> bool {
+        self.record
+    }
+
+    pub fn trace(&self) -> Option<&trace::Target> {
+        self.trace.as_ref()
+    }
+
+    // How many threads to use, if any more than one
+    pub fn parallel(&self) -> Option<usize> {
+        self.parallel
+    }
+
+    // How the input read from this source is keyed in the answers file
+    pub fn answers_key(&self, text: &str) -> String {
+        self.source
+            .answers_key(&self.bundle_dir.join("input.txt"), text)
+    }
+
+    pub fn runs_part(&self, part: Part) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    pub fn try_input(&self) -> Result<String, InputError> {
+        self.source.read(&self.bundle_dir.join("input.txt"))
+    }
+
+    // As `try_input`, but exits with an error message if the input can't be read
+    pub fn input(&self) -> String {
+        self.try_input().unwrap_or_else(|error| exit_with(&error))
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, ArgsError> {
+    value
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| ArgsError::InvalidPart(value.to_string()))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+// A hash that stays the same from one build to the next, unlike the standard library's
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| InputError::Read(path.to_path_buf(), error))
+}
+
+fn exit_with(error: &InputError) -> ! {
+    eprintln!("error: {error}");
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_only(args: &[&str], allowed: &[&str]) -> Result<Option<Args>, ArgsError> {
+        Args::parse_only(".", args.iter().map(|arg| arg.to_string()), allowed)
+    }
+
+    #[test]
+    fn rejects_options_a_command_ignores() {
+        let args = parse_only(&["in.txt", "--part", "2"], &["--part"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(args.part(), Some(Part::Two));
+
+        assert_eq!(
+            parse_only(&["--part=1", "--trace=out.txt"], &["--part"]).unwrap_err(),
+            ArgsError::UnexpectedArgument("--trace".to_string())
+        );
+        assert_eq!(
+            parse_only(&["--json", "--parallel"], &[]).unwrap_err(),
+            ArgsError::UnexpectedArgument("--json".to_string())
+        );
+        assert!(parse_only(&["--json", "--help"], &[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn only_the_bundled_input_is_keyed_as_input() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .with_file_name("target")
+            .join("answers-keys");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("day07")).unwrap();
+        fs::create_dir_all(dir.join("colleague")).unwrap();
+
+        let bundled = dir.join("day07").join("input.txt");
+        let theirs = dir.join("colleague").join("input.txt");
+        fs::write(&bundled, "32T3K 765\n").unwrap();
+        fs::write(&theirs, "KK677 28\n").unwrap();
+
+        let key = |source: InputSource, text| source.answers_key(&bundled, text);
+
+        assert_eq!(key(InputSource::Bundled, "32T3K 765\n"), "input");
+        assert_eq!(
+            key(
+                InputSource::Path(dir.join("colleague/../day07/input.txt")),
+                "32T3K 765\n"
+            ),
+            "input"
+        );
+
+        let their_key = key(InputSource::Path(theirs.clone()), "KK677 28\n");
+        assert!(their_key.starts_with("input-"));
+        assert_ne!(their_key, key(InputSource::Path(theirs), "KK677 29\n"));
+        assert_eq!(
+            key(InputSource::Stdin, "KK677 28\r\n"),
+            their_key.replacen("input", "stdin", 1)
+        );
+    }
+}
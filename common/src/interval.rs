@@ -0,0 +1,252 @@
+use std::ops::Range;
+
+// A set of integers, kept as sorted half-open ranges that neither overlap nor touch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // How many integers are in the set
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end.abs_diff(range.start))
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    // Everything in `self` that isn't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            for removed in &other.ranges {
+                if removed.end <= start {
+                    continue;
+                }
+
+                if removed.start >= range.end {
+                    break;
+                }
+
+                if removed.start > start {
+                    ranges.push(start..removed.start);
+                }
+
+                start = start.max(removed.end);
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    // Splits into everything below `at`, and everything from `at` upwards
+    pub fn split_at(&self, at: i64) -> (Self, Self) {
+        (
+            self.intersection(&Self::from(i64::MIN..at)),
+            self.intersection(&Self::from(at..i64::MAX)),
+        )
+    }
+
+    // Adds `offset` to everything in the set
+    pub fn translate(&self, offset: i64) -> Self {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+
+    fn normalise(&mut self) {
+        self.ranges.retain(|range| !range.is_empty());
+        self.ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(self.ranges.len());
+
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        self.ranges = merged;
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> Self {
+        let mut set = Self {
+            ranges: ranges.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+// Every combination of a value from each axis: a box in `N` dimensions, if each axis
+// is a single range
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalBox<const N: usize> {
+    axes: [IntervalSet; N],
+}
+
+impl<const N: usize> IntervalBox<N> {
+    pub fn new(axes: [IntervalSet; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn axis(&self, axis: usize) -> &IntervalSet {
+        &self.axes[axis]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(IntervalSet::is_empty)
+    }
+
+    // How many points are in the box
+    pub fn volume(&self) -> u128 {
+        self.axes
+            .iter()
+            .map(|axis| u128::from(axis.len()))
+            .product()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            axes: std::array::from_fn(|i| self.axes[i].intersection(&other.axes[i])),
+        }
+    }
+
+    // Splits along one axis into the points below `at`, and those from `at` upwards
+    pub fn split_at(&self, axis: usize, at: i64) -> (Self, Self) {
+        let (below, above) = self.axes[axis].split_at(at);
+
+        let mut lower = self.clone();
+        lower.axes[axis] = below;
+
+        let mut upper = self.clone();
+        upper.axes[axis] = above;
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let merged = set(&[5..8, 0..3, 3..4, 7..10, 12..12]);
+
+        assert_eq!(merged.ranges(), [0..4, 5..10]);
+        assert_eq!(merged.len(), 9);
+        assert_eq!((merged.min(), merged.max()), (Some(0), Some(9)));
+        assert!(merged.contains(9) && !merged.contains(4));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = IntervalSet::from(5..25);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert_eq!(
+            a.split_at(22),
+            (set(&[0..10, 20..22]), IntervalSet::from(22..30))
+        );
+        assert_eq!(a.translate(-5), set(&[-5..5, 15..25]));
+    }
+
+    #[test]
+    fn boxes_split_along_an_axis() {
+        let cube = IntervalBox::new([
+            IntervalSet::from(1..5),
+            IntervalSet::from(1..5),
+            IntervalSet::from(1..5),
+        ]);
+        let (lower, upper) = cube.split_at(1, 2);
+
+        assert_eq!(cube.volume(), 64);
+        assert_eq!((lower.volume(), upper.volume()), (16, 48));
+        assert!(lower.intersection(&upper).is_empty());
+    }
+}
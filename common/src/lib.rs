@@ -0,0 +1,19 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cycle;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod number;
+pub mod parallel;
+pub mod parse;
+pub mod polynomial;
+pub mod reference;
+pub mod solution;
+pub mod trace;
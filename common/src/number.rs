@@ -0,0 +1,206 @@
+use std::ops::{Div, Rem};
+
+pub trait Integer: Copy + Ord + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    fn wrapping_rem(self, other: Self) -> Self;
+
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($abs:expr; $($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$type>::checked_mul(self, other)
+                }
+
+                fn wrapping_rem(self, other: Self) -> Self {
+                    <$type>::wrapping_rem(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(Some; u8, u16, u32, u64, u128, usize);
+impl_integer!(|n: Self| n.checked_abs(); i8, i16, i32, i64, i128, isize);
+
+// Never negative; gcd(0, 0) is 0. `None` if the result doesn't fit in `T`, which only
+// happens when both are `T::MIN` or 0
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // Only `T::MIN % -1` wraps, and its remainder is 0 all the same
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+
+    a.checked_abs()
+}
+
+// `None` if the result doesn't fit in `T`
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+// Returns (g, x, y) such that a * x + b * y = g = gcd(a, b)
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// Solves x ≡ residue (mod modulus) for every (residue, modulus) given, returning the
+// solution as (residue, modulus) again. The moduli needn't be coprime, in which case
+// there may be no solution, giving `None`; so does overflow
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences.into_iter().try_fold(
+        (0, 1),
+        |(residue, modulus), (next_residue, next_modulus)| {
+            assert!(next_modulus > 0, "moduli must be positive");
+
+            // Both residues are then below their moduli, so their difference can't overflow
+            let next_residue = next_residue.rem_euclid(next_modulus);
+            let (g, p, _) = extended_gcd(modulus, next_modulus);
+            let difference = next_residue - residue;
+
+            if difference % g != 0 {
+                return None;
+            }
+
+            // Step through the current solutions until one also satisfies the next congruence
+            let step = next_modulus / g;
+            let steps = (difference / g)
+                .rem_euclid(step)
+                .checked_mul(p.rem_euclid(step))?
+                % step;
+
+            let combined = (modulus / g).checked_mul(next_modulus)?;
+            let residue = modulus
+                .checked_mul(steps)?
+                .checked_add(residue)?
+                .rem_euclid(combined);
+
+            Some((residue, combined))
+        },
+    )
+}
+
+// Something that happens first at `offset` and then every `period` after that
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub offset: u64,
+    pub period: u64,
+}
+
+impl Cycle {
+    pub fn new(offset: u64, period: u64) -> Self {
+        assert!(period > 0, "a cycle must have a period");
+
+        Self { offset, period }
+    }
+
+    pub fn happens_at(&self, time: u64) -> bool {
+        time >= self.offset && (time - self.offset).is_multiple_of(self.period)
+    }
+}
+
+// The first time at which every one of the cycles happens at once, if ever
+pub fn first_common_time(cycles: &[Cycle]) -> Option<u64> {
+    let (residue, modulus) = crt(cycles
+        .iter()
+        .map(|cycle| (i128::from(cycle.offset), i128::from(cycle.period))))?;
+
+    // Solutions from before every cycle has started don't count
+    let started = i128::from(cycles.iter().map(|cycle| cycle.offset).max().unwrap_or(0));
+    let time = if residue >= started {
+        residue
+    } else {
+        residue + (started - residue + modulus - 1) / modulus * modulus
+    };
+
+    u64::try_from(time).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u32, 18), Some(6));
+        assert_eq!(gcd(-12i64, 18), Some(6));
+        assert_eq!(gcd(0u8, 0), Some(0));
+        assert_eq!(gcd(i64::MIN, 6), Some(2));
+        assert_eq!(gcd(i64::MIN, -1), Some(1));
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm_all([2usize, 3, 4, 5]), Some(60));
+        assert_eq!(lcm(200u8, 3), None);
+        assert_eq!(lcm(i64::MIN, 1), None);
+    }
+
+    #[test]
+    fn extended_gcd_finds_bezout_coefficients() {
+        for (a, b) in [(240, 46), (46, 240), (-7, 3), (0, 5)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(Some(g), gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn crt_with_and_without_coprime_moduli() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([(-1, 5), (i128::MIN, 7)]), Some((19, 35)));
+        assert_eq!(crt([(3, 5), (i128::MAX, 5)]), None);
+    }
+
+    #[test]
+    fn cycles_with_offsets() {
+        // The cycles meet at 4 before the second one has started
+        let cycles = [Cycle::new(0, 2), Cycle::new(7, 3)];
+        assert_eq!(first_common_time(&cycles), Some(10));
+        assert!(cycles.iter().all(|cycle| cycle.happens_at(10)));
+
+        assert_eq!(
+            first_common_time(&[Cycle::new(3, 4), Cycle::new(6, 4)]),
+            None
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::trace;
+
+// How many threads `map` may use; 1 (the default) keeps everything on the calling thread
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    // Set on `map`'s own threads, so that a `map` inside another runs on the thread
+    // it is called from instead of starting even more threads
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+// Lets `map` spread its work over up to `threads` threads for the rest of the run
+pub fn enable(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+// As many threads as the machine can run at once
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, usize::from)
+}
+
+// How many threads `map` would use if called here
+pub fn threads() -> usize {
+    if WORKER.get() {
+        1
+    } else {
+        THREADS.load(Ordering::Relaxed)
+    }
+}
+
+// `items.iter().map(f).collect()`, but with the items handed out to threads as they
+// become free. The results are in the same order as the items however the work was
+// shared out, so that answers and output don't depend on the number of threads
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = threads().min(items.len());
+
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+    let context = trace::context();
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                WORKER.set(true);
+
+                if let Some((day, part)) = context {
+                    trace::set_context(day, part);
+                }
+
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    results.lock().unwrap().push((index, result));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_results_in_order() {
+        enable(4);
+
+        let items = (0..100).collect::<Vec<u64>>();
+        let squares = map(&items, |n| {
+            // Later items finish first
+            thread::sleep(std::time::Duration::from_micros(100 - n));
+            n * n
+        });
+
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+
+        let nested = map(&[1, 2, 3], |_| threads());
+        assert_eq!(nested, [1, 1, 1]);
+    }
+}
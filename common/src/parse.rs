@@ -0,0 +1,373 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+    iter,
+    str::FromStr,
+};
+
+// Where and why an input couldn't be parsed. Lines and columns count from 1,
+// columns in characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Display) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    // For input that stops before something that was expected
+    pub fn end_of_input(input: &str, message: impl Display) -> Self {
+        let line = input.lines().count().max(1);
+        let column = input.lines().last().map_or(0, |last| last.chars().count()) + 1;
+
+        Self::new(line, column, "", message)
+    }
+
+    // A compiler-style report pointing at the offending text, e.g.
+    //
+    //   error: expected a card, one of 23456789TJQKA
+    //    --> input.txt:3:5
+    //     |
+    //   3 | 32T3X 765
+    //     |     ^
+    pub fn render(&self, input_name: &str, input: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {}\n{gutter}--> {input_name}:{}:{}\n{gutter} |\n{} | {source_line}\n{gutter} | {padding}{carets}",
+            self.message, self.line, self.column, self.line,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, " (found end of line)")
+        } else {
+            write!(f, " (found `{}`)", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Puzzle inputs saved on Windows or by hand may have CRLF line endings or
+// trailing blank lines; parsers only ever see `\n` and exactly one final newline
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim_end_matches(['\r', '\n']);
+
+    if !input.contains('\r') && input.len() == trimmed.len() + 1 {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalised = trimmed.replace("\r\n", "\n");
+    normalised.push('\n');
+
+    Cow::Owned(normalised)
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+// Runs of lines separated by a blank line, for inputs that come in sections. Any
+// further blank lines are reported rather than taken as empty sections
+pub fn blocks(input: &str) -> impl Iterator<Item = Result<Vec<Line<'_>>, ParseError>> {
+    let mut lines = lines(input).peekable();
+
+    iter::from_fn(move || {
+        lines.peek()?;
+
+        let mut block = Vec::new();
+
+        for line in lines.by_ref() {
+            if !line.text.is_empty() {
+                block.push(line);
+            } else if block.is_empty() {
+                return Some(Err(
+                    line.error_at_end("expected only one blank line between sections")
+                ));
+            } else {
+                break;
+            }
+        }
+
+        Some(Ok(block))
+    })
+}
+
+// Each line as a row of characters mapped by `f`, all of which must be the same length
+pub fn char_rows<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    expected: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for line in lines {
+        let row = line.parse_chars(line.text, expected, &mut f)?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(line.error(
+                    line.text,
+                    format!("expected a row of {} cells, like the first", first.len()),
+                ));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+// A single line of input that knows where it is, so that errors about any
+// part of it can be reported by position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // `fragment` should be a slice of this line; anything else is reported
+    // as being at the end of the line
+    pub fn error(&self, fragment: &str, message: impl Display) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + fragment.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+
+        let column = self.text[..offset].chars().count() + 1;
+
+        ParseError::new(self.number, column, fragment, message)
+    }
+
+    pub fn error_at_end(&self, message: impl Display) -> ParseError {
+        self.error(&self.text[self.text.len()..], message)
+    }
+
+    pub fn split_once(
+        &self,
+        within: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        within
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(within, format!("expected `{delimiter}`")))
+    }
+
+    // Splits a `<keyword> <id>: <rest>` line, such as `Game 3: ...`, into the id and the rest
+    pub fn header<T: FromStr>(
+        &self,
+        keyword: &str,
+        what: &str,
+    ) -> Result<(T, &'a str), ParseError> {
+        let (header, rest) = self.split_once(self.text, ":")?;
+
+        let id = header
+            .strip_prefix(keyword)
+            .filter(|id| id.starts_with(' '))
+            .ok_or_else(|| self.error(header, format!("expected `{keyword} <id>`")))?;
+
+        Ok((self.parse(id, what)?, rest))
+    }
+
+    // The rest of a `<label>: <rest>` line, such as `seeds: ...`
+    pub fn labelled(&self, label: &str) -> Result<&'a str, ParseError> {
+        let (found, rest) = self.split_once(self.text, ":")?;
+
+        if found != label {
+            return Err(self.error(found, format!("expected `{label}`")));
+        }
+
+        Ok(rest)
+    }
+
+    pub fn parse<T: FromStr>(&self, fragment: &'a str, what: &str) -> Result<T, ParseError> {
+        fragment
+            .trim()
+            .parse()
+            .map_err(|_| self.error(fragment.trim(), format!("expected {what}")))
+    }
+
+    // Whitespace-separated values within `fragment`
+    pub fn parse_all<T: FromStr>(
+        &self,
+        fragment: &'a str,
+        what: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        fragment
+            .split_ascii_whitespace()
+            .map(|value| self.parse(value, what))
+            .collect()
+    }
+
+    // Values within `fragment` separated by `separator`, which may have spaces around them
+    pub fn parse_list<T: FromStr>(
+        &self,
+        fragment: &'a str,
+        separator: &str,
+        what: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        fragment
+            .split(separator)
+            .map(|value| self.parse(value, what))
+            .collect()
+    }
+
+    // As `parse_list`, but there must be exactly `N` values
+    pub fn parse_array<T: FromStr, const N: usize>(
+        &self,
+        fragment: &'a str,
+        separator: &str,
+        what: &str,
+    ) -> Result<[T; N], ParseError> {
+        let values = self.parse_list(fragment, separator, what)?;
+        let found = values.len();
+
+        values.try_into().map_err(|_| {
+            self.error(
+                fragment.trim(),
+                format!("expected {N} values separated by `{separator}`, found {found}"),
+            )
+        })
+    }
+
+    // Each character in turn, mapped by `f`; a `None` is reported as not being `expected`
+    pub fn parse_chars<T>(
+        &self,
+        fragment: &'a str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        fragment
+            .char_indices()
+            .map(|(i, c)| {
+                f(c).ok_or_else(|| {
+                    self.error(
+                        &fragment[i..i + c.len_utf8()],
+                        format!("expected {expected}"),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    // Parses the single character `fragment`
+    pub fn parse_char<T>(
+        &self,
+        fragment: &'a str,
+        expected: &str,
+        f: impl FnOnce(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        let mut chars = fragment.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => f(c),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(fragment, format!("expected {expected}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_crlf_and_trailing_blank_lines() {
+        assert_eq!(normalise("a\r\nb\r\n\r\n\r\n"), "a\nb\n");
+        assert_eq!(normalise("a\nb"), "a\nb\n");
+        assert!(matches!(normalise("a\nb\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn reports_the_column_of_a_fragment() {
+        let line = lines("one\nGame 12: 3 blue\n").nth(1).unwrap();
+        let (_, reveal) = line.split_once(line.text, ": ").unwrap();
+
+        let error = line.parse::<u32>(&reveal[2..], "a number").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 12));
+        assert_eq!(error.text, "blue");
+    }
+
+    #[test]
+    fn splits_into_blocks() {
+        let texts = blocks("a\nb\n\nc\n")
+            .map(|block| block.map(|lines| lines.iter().map(|line| line.text).collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>, _>>();
+
+        assert_eq!(texts, Ok(vec![vec!["a", "b"], vec!["c"]]));
+
+        let error = blocks("a\n\n\nc\n").nth(1).unwrap().unwrap_err();
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn parses_headers_and_lists() {
+        let line = lines("Card  7: 1, 2 ,3\n").next().unwrap();
+        let (id, rest) = line.header::<u32>("Card", "a card number").unwrap();
+
+        assert_eq!(id, 7);
+        assert_eq!(
+            line.parse_list::<u32>(rest, ",", "a number"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            line.parse_array::<u32, 3>(rest, ",", "a number"),
+            Ok([1, 2, 3])
+        );
+        assert_eq!(
+            line.parse_array::<u32, 2>(rest, ",", "a number")
+                .unwrap_err()
+                .message,
+            "expected 2 values separated by `,`, found 3"
+        );
+        assert_eq!(
+            line.header::<u32>("Game", "a game id").unwrap_err().column,
+            1
+        );
+        assert_eq!(line.labelled("Card").unwrap_err().text, "Card  7");
+
+        let rows = char_rows(lines("#.\n.#\n"), "`.` or `#`", |c| Some(c == '#'));
+        assert_eq!(rows, Ok(vec![vec![true, false], vec![false, true]]));
+        assert!(char_rows(lines("#.\n.\n"), "`.` or `#`", |c| Some(c == '#')).is_err());
+    }
+
+    #[test]
+    fn renders_a_caret_under_the_offending_text() {
+        let error = ParseError::new(2, 3, "X", "expected a digit");
+
+        assert_eq!(
+            error.render("input.txt", "123\n45X\n"),
+            "error: expected a digit\n --> input.txt:2:3\n  |\n2 | 45X\n  |   ^"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::{self, Display, Formatter};
+
+// A polynomial fitted exactly to a sequence of integers, as the sequence's first
+// value and then the first of each row of differences under it (Newton's forward
+// differences). Integer sequences have integer differences, so this needs no fractions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<i128>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolynomialError {
+    // The differences didn't settle on zero while there were at least two of them
+    // left to confirm it; `values` is how many values there were
+    NotPolynomial { values: usize },
+    Overflow,
+}
+
+impl Display for PolynomialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPolynomial { values } if *values < 3 => {
+                write!(
+                    f,
+                    "at least 3 values are needed to fit a polynomial, found {values}"
+                )
+            }
+            Self::NotPolynomial { values } => write!(
+                f,
+                "the {values} values given don't follow a polynomial of degree {} or less",
+                values.saturating_sub(3)
+            ),
+            Self::Overflow => write!(f, "the value is too large to calculate"),
+        }
+    }
+}
+
+impl std::error::Error for PolynomialError {}
+
+impl Polynomial {
+    // Fitting a polynomial of degree `d` takes `d + 3` values: `d + 1` to fix it and
+    // two more to check it, so that a single zero difference isn't taken as proof
+    pub fn fit<T: Copy + Into<i128>>(values: &[T]) -> Result<Self, PolynomialError> {
+        let mut row = values
+            .iter()
+            .map(|value| (*value).into())
+            .collect::<Vec<i128>>();
+        let mut differences = Vec::new();
+
+        while row.len() < 2 || row.iter().any(|value| *value != 0) {
+            if row.len() < 2 {
+                return Err(PolynomialError::NotPolynomial {
+                    values: values.len(),
+                });
+            }
+
+            differences.push(row[0]);
+
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<_>>()
+                .ok_or(PolynomialError::Overflow)?;
+        }
+
+        Ok(Self { differences })
+    }
+
+    // The zero polynomial counts as degree 0
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    // `x` indexes into the sequence that was fitted, so the value after the last is at
+    // `values.len()` and the one before the first is at -1
+    pub fn value_at(&self, x: i128) -> Result<i128, PolynomialError> {
+        // The sum of each difference times `x` choose `k`
+        let mut value = 0i128;
+        let mut choose = 1i128;
+
+        for (k, difference) in (0..).zip(&self.differences) {
+            if k > 0 {
+                // Always divides exactly, as (x choose k - 1) * (x - k + 1) = (x choose k) * k
+                choose = x
+                    .checked_sub(k - 1)
+                    .and_then(|factor| choose.checked_mul(factor))
+                    .ok_or(PolynomialError::Overflow)?
+                    / k;
+            }
+
+            value = difference
+                .checked_mul(choose)
+                .and_then(|term| value.checked_add(term))
+                .ok_or(PolynomialError::Overflow)?;
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_and_extrapolates_both_ways() {
+        // n^2 + 1
+        let polynomial = Polynomial::fit(&[1, 2, 5, 10, 17]).unwrap();
+
+        assert_eq!(polynomial.degree(), 2);
+        assert_eq!(polynomial.value_at(5), Ok(26));
+        assert_eq!(polynomial.value_at(-1), Ok(2));
+        assert_eq!(polynomial.value_at(-10), Ok(101));
+        assert_eq!(polynomial.value_at(1_000_000), Ok(1_000_000_000_001));
+
+        assert_eq!(Polynomial::fit(&[0i64, 0]).unwrap().degree(), 0);
+        assert_eq!(Polynomial::fit(&[0i64, 0]).unwrap().value_at(3), Ok(0));
+    }
+
+    #[test]
+    fn needs_two_zero_differences() {
+        assert_eq!(
+            Polynomial::fit(&[1, 2, 5, 10]),
+            Err(PolynomialError::NotPolynomial { values: 4 })
+        );
+        assert_eq!(
+            Polynomial::fit(&[1, 2, 4, 8, 16, 32]),
+            Err(PolynomialError::NotPolynomial { values: 6 })
+        );
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let cubic = Polynomial::fit(&[0i64, 1, 8, 27, 64, 125]).unwrap();
+
+        assert_eq!(cubic.value_at(1 << 20), Ok(1 << 60));
+        assert_eq!(cubic.value_at(1 << 50), Err(PolynomialError::Overflow));
+    }
+}
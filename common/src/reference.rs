@@ -0,0 +1,211 @@
+use std::{
+    env, fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    generate::{self, Generate},
+    parse,
+    solution::{Answer, Part},
+};
+
+// A day with a slow but obviously correct way of getting its answers, to check the
+// real solution's shortcuts against. References work from the input text rather than
+// the parsed input, so that they don't share any of the solution's assumptions, and
+// only need to cope with inputs like the day's generator makes
+pub trait Reference: Generate {
+    // Small enough for the reference to solve quickly, even in a debug build
+    const REFERENCE_SIZE: usize;
+
+    // `Answer::Unavailable` for a part without a reference, e.g. because brute force
+    // would never finish at any size
+    fn reference(input: &str, part: Part) -> Answer;
+}
+
+// Where the solution and something slower but simpler disagree about an input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement {
+    pub what: String,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+// Solves generated inputs for each seed both ways, and panics if they ever disagree
+pub fn assert_matches_reference<R: Reference>(seeds: Range<u64>) {
+    assert_agrees::<R>(R::REFERENCE_SIZE, seeds, |input| {
+        let parsed = R::parse(input).unwrap();
+
+        Part::ALL
+            .into_iter()
+            .filter_map(|part| {
+                let expected = R::reference(input, part);
+                let actual = R::part(&parsed, part);
+
+                (expected != Answer::Unavailable && expected != actual).then(|| Disagreement {
+                    what: format!("part {part}"),
+                    expected,
+                    actual,
+                })
+            })
+            .collect()
+    });
+}
+
+// Runs `check` on the generated input for each seed, for days that need to compare
+// something other than their answers. Every input with a disagreement is saved, so
+// that it can be rerun with `aoc run <DAY> --input <PATH>` after the test has failed
+pub fn assert_agrees<G: Generate>(
+    size: usize,
+    seeds: Range<u64>,
+    check: impl Fn(&str) -> Vec<Disagreement>,
+) {
+    let mut failures = Vec::new();
+
+    for seed in seeds {
+        let input = generate::generate::<G>(seed, size);
+        let disagreements = check(&parse::normalise(&input));
+
+        if disagreements.is_empty() {
+            continue;
+        }
+
+        let path = failures_dir().join(format!("day{:02}-size{size}-seed{seed}.txt", G::DAY));
+        let saved = fs::create_dir_all(failures_dir())
+            .and_then(|()| fs::write(&path, &input))
+            .map_or_else(
+                |error| format!("could not be saved to `{}`: {error}", path.display()),
+                |()| format!("saved to `{}`", path.display()),
+            );
+
+        failures.push(format!("seed {seed}, input {saved}"));
+
+        for Disagreement {
+            what,
+            expected,
+            actual,
+        } in disagreements
+        {
+            failures.push(format!("    {what}: expected {expected}, found {actual}"));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "day {} disagrees on generated inputs of size {size}:\n{}",
+        G::DAY,
+        failures.join("\n")
+    );
+}
+
+// Alongside everything else cargo builds, so that it is never checked in
+fn failures_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(
+            || Path::new(env!("CARGO_MANIFEST_DIR")).with_file_name("target"),
+            PathBuf::from,
+        )
+        .join("differential")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng};
+
+    use super::*;
+    use crate::{
+        parse::ParseError,
+        solution::{Runner, Solution, SolutionRunner},
+    };
+
+    // Sums a list of numbers, but skips sevens in part 2
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 99;
+
+        type Parsed<'input> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+            parse::lines(input)
+                .map(|line| line.parse(line.text, "a number"))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Parsed<'_>) -> Answer {
+            numbers.iter().sum::<u32>().into()
+        }
+
+        fn part2(numbers: &Self::Parsed<'_>) -> Answer {
+            numbers.iter().filter(|n| **n != 7).sum::<u32>().into()
+        }
+    }
+
+    impl Generate for Sum {
+        const DEFAULT_SIZE: usize = 10;
+
+        fn generate(rng: &mut StdRng, size: usize) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.gen_range(0..10)))
+                .collect()
+        }
+    }
+
+    impl Reference for Sum {
+        const REFERENCE_SIZE: usize = 3;
+
+        fn reference(input: &str, _: Part) -> Answer {
+            input
+                .lines()
+                .map(|line| line.parse::<u32>().unwrap())
+                .sum::<u32>()
+                .into()
+        }
+    }
+
+    #[test]
+    fn saves_inputs_that_disagree() {
+        // Some of these seeds have a seven, and some don't
+        let seeds = 0..20;
+        let failing = seeds
+            .clone()
+            .filter(|seed| generate::generate::<Sum>(*seed, 3).contains('7'))
+            .collect::<Vec<_>>();
+
+        assert!(!failing.is_empty() && failing.len() < 20);
+
+        let message = std::panic::catch_unwind(|| assert_matches_reference::<Sum>(seeds))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+
+        for seed in failing {
+            let path = failures_dir().join(format!("day99-size3-seed{seed}.txt"));
+
+            assert!(message.contains(&format!("seed {seed}, input saved")));
+            assert_eq!(
+                fs::read_to_string(&path).unwrap(),
+                generate::generate::<Sum>(seed, 3)
+            );
+
+            fs::remove_file(path).unwrap();
+        }
+
+        assert!(!message.contains("part 1"));
+    }
+
+    #[test]
+    fn runners_refuse_sizes_with_no_input() {
+        let runner = SolutionRunner::<Sum>::new();
+
+        assert_eq!(
+            runner.generate(0, Some(0)),
+            Err("the size must be at least 1".to_string())
+        );
+        assert_eq!(
+            runner.generate(0, Some(3)),
+            Ok(generate::generate::<Sum>(0, 3))
+        );
+        assert_eq!(runner.generate(0, None).unwrap().lines().count(), 10);
+    }
+}
@@ -0,0 +1,418 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{self, Answers, Status},
+    bench::{DayBench, Measure, Sampling},
+    generate::{self, Generate},
+    input::Args,
+    json::Json,
+    parallel,
+    parse::{self, ParseError},
+    trace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // For parts that don't exist, e.g. day 25 part 2
+    Unavailable,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Unavailable => write!(f, "n/a"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(value: $type) -> Self {
+                    Self::Number(value as i128)
+                }
+            }
+
+            impl PartialEq<$type> for Answer {
+                fn eq(&self, other: &$type) -> bool {
+                    matches!(self, Self::Number(number) if *number == *other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(number) => Json::Integer(*number),
+            Answer::Text(text) => Json::from(text.as_str()),
+            Answer::Unavailable => Json::Null,
+        }
+    }
+}
+
+// A day's puzzle: parsing is kept separate from solving so that the two can be timed separately
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed<'input>;
+
+    // `input` has already been normalised; see `parse::normalise`
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn part(parsed: &Self::Parsed<'_>, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(parsed),
+            Part::Two => Self::part2(parsed),
+        }
+    }
+
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        let input = parse::normalise(input);
+        let parsed = Self::parse(&input)?;
+
+        Ok(Self::part(&parsed, part))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+        Self::solve(input, Part::One)
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+        Self::solve(input, Part::Two)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl Run {
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&PartRun> {
+        self.parts.iter().find(|run| run.part == part)
+    }
+
+    pub fn statuses<'a>(
+        &'a self,
+        input: &'a str,
+        known: &'a Answers,
+    ) -> impl Iterator<Item = (&'a PartRun, Status)> + 'a {
+        self.parts.iter().map(move |part_run| {
+            let status = known.check(self.day, input, part_run.part, &part_run.answer);
+            (part_run, status)
+        })
+    }
+
+    // One record per part. `input` names the input as it is keyed in `known`;
+    // `matched` is null when there is no known answer to compare with
+    pub fn to_json(&self, input: &str, known: &Answers) -> Vec<Json> {
+        self.statuses(input, known)
+            .map(|(part_run, status)| {
+                let expected = known.get(self.day, input, part_run.part);
+
+                Json::object([
+                    ("day", Json::from(self.day)),
+                    ("part", Json::from(part_run.part.number())),
+                    ("input", Json::from(input)),
+                    ("answer", Json::from(&part_run.answer)),
+                    ("expected", Json::from(expected)),
+                    (
+                        "matched",
+                        Json::from(expected.map(|expected| *expected == part_run.answer)),
+                    ),
+                    ("status", Json::from(status.name())),
+                    ("parse_ns", Json::from(self.parse_elapsed.as_nanos())),
+                    ("elapsed_ns", Json::from(part_run.elapsed.as_nanos())),
+                ])
+            })
+            .collect()
+    }
+}
+
+// Object-safe view of a `Solution`, so that days can be registered side by side
+pub trait Runner: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError>;
+
+    // Times parsing and each part separately, repeating each as `sampling` allows
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        sampling: &Sampling,
+    ) -> Result<DayBench, ParseError>;
+
+    // A made up input, at the day's default size if none is given. Fails if there's no
+    // input of that size
+    fn generate(&self, seed: u64, size: Option<usize>) -> Result<String, String>;
+}
+
+pub struct SolutionRunner<S>(PhantomData<fn() -> S>);
+
+impl<S> SolutionRunner<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for SolutionRunner<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Generate> Runner for SolutionRunner<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        let input = parse::normalise(input);
+
+        trace::set_context(S::DAY, None);
+        let start = Instant::now();
+        let parsed = S::parse(&input)?;
+        let parse_elapsed = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                trace::set_context(S::DAY, Some(part));
+                let start = Instant::now();
+                let answer = S::part(&parsed, part);
+
+                PartRun {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(Run {
+            day: S::DAY,
+            parse_elapsed,
+            parts,
+        })
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        sampling: &Sampling,
+    ) -> Result<DayBench, ParseError> {
+        let input = parse::normalise(input);
+
+        // Parse once up front so that errors are reported rather than timed
+        let parsed = S::parse(&input)?;
+
+        let mut measurements = vec![(Measure::Parse, sampling.measure(|| S::parse(&input)))];
+
+        for &part in parts {
+            measurements.push((
+                Measure::Part(part),
+                sampling.measure(|| S::part(&parsed, part)),
+            ));
+        }
+
+        Ok(DayBench {
+            day: S::DAY,
+            measurements,
+        })
+    }
+
+    fn generate(&self, seed: u64, size: Option<usize>) -> Result<String, String> {
+        let size = size.unwrap_or(S::DEFAULT_SIZE);
+        S::check_size(size)?;
+
+        Ok(generate::generate::<S>(seed, size))
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+// Entry point for a day's own binary; `bundle_dir` is where its input.txt lives
+pub fn main<S: Generate>(bundle_dir: &str) {
+    let args = Args::from_env(bundle_dir);
+    let input = args.input();
+
+    if let Some(target) = args.trace() {
+        if let Err(error) = trace::start(target) {
+            eprintln!("error: could not start tracing: {error}");
+            process::exit(1);
+        }
+    }
+
+    if let Some(threads) = args.parallel() {
+        parallel::enable(threads);
+    }
+
+    let input = parse::normalise(&input);
+
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|part| args.runs_part(*part))
+        .collect::<Vec<_>>();
+
+    let run = SolutionRunner::<S>::new()
+        .run(&input, &parts)
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error.render(&args.source().name(), &input));
+            process::exit(1);
+        });
+
+    let answers_path = answers::answers_path(Path::new(bundle_dir));
+    let known = Answers::load_or_warn(&answers_path);
+    let input_key = args.answers_key(&input);
+
+    if args.json() {
+        println!("{}", Json::Array(run.to_json(&input_key, &known)));
+    } else {
+        for (part_run, status) in run.statuses(&input_key, &known) {
+            if part_run.answer != Answer::Unavailable {
+                println!("Part {}: {} ({status})", part_run.part, part_run.answer);
+            }
+        }
+    }
+
+    if args.record() {
+        if let Err(error) = answers::record_runs(&answers_path, &[(run, input_key)]) {
+            eprintln!("error: {error}");
+            process::exit(1);
+        }
+    } else if run
+        .statuses(&input_key, &known)
+        .any(|(_, status)| matches!(status, Status::Fail { .. }))
+    {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_records_say_whether_the_answer_matched() {
+        let known = Answers::parse("[day07.input]\npart1 = 6440\n").unwrap();
+        let run = Run {
+            day: 7,
+            parse_elapsed: Duration::from_nanos(10),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Answer::from(6440),
+                    elapsed: Duration::from_nanos(20),
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Answer::from(5905),
+                    elapsed: Duration::from_nanos(30),
+                },
+            ],
+        };
+
+        let records = run
+            .to_json("input", &known)
+            .iter()
+            .map(Json::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            records,
+            [
+                r#"{"day":7,"part":1,"input":"input","answer":6440,"expected":6440,"matched":true,"status":"pass","parse_ns":10,"elapsed_ns":20}"#,
+                r#"{"day":7,"part":2,"input":"input","answer":5905,"expected":null,"matched":null,"status":"unknown","parse_ns":10,"elapsed_ns":30}"#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    cell::Cell,
+    fmt::{Debug, Write as _},
+    fs::File,
+    io::{self, LineWriter, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use crate::solution::Part;
+
+// Logs what a solution is doing as it goes, one line per event, e.g.
+//
+//   day=4 part=1 event=card number=3 winners=[53, 59, 84] points=4
+//
+// Nothing is formatted unless tracing has been turned on, so that trace points cost
+// next to nothing the rest of the time. The answers themselves are never traced
+#[macro_export]
+macro_rules! trace {
+    ($event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit($event, &[$((stringify!($key), &$value)),*]);
+        }
+    };
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+thread_local! {
+    // Which day and part the events on this thread come from; no part while parsing
+    static CONTEXT: Cell<Option<(u8, Option<Part>)>> = const { Cell::new(None) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Stderr,
+    File(PathBuf),
+}
+
+// Turns tracing on for the rest of the run
+pub fn start(target: &Target) -> io::Result<()> {
+    match target {
+        Target::Stderr => to_writer(io::stderr()),
+        Target::File(path) => to_writer(LineWriter::new(File::create(path)?)),
+    }
+
+    Ok(())
+}
+
+pub fn to_writer(writer: impl Write + Send + 'static) {
+    *SINK.lock().unwrap() = Some(Box::new(writer));
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Tags the events that follow on this thread with the day and part being solved
+pub fn set_context(day: u8, part: Option<Part>) {
+    CONTEXT.set(Some((day, part)));
+}
+
+pub fn context() -> Option<(u8, Option<Part>)> {
+    CONTEXT.get()
+}
+
+// Called by `trace!`; values are written with `Debug`, so that strings are quoted
+pub fn emit(event: &str, fields: &[(&str, &dyn Debug)]) {
+    let mut line = String::new();
+
+    if let Some((day, part)) = CONTEXT.get() {
+        write!(line, "day={day} ").unwrap();
+
+        if let Some(part) = part {
+            write!(line, "part={part} ").unwrap();
+        }
+    }
+
+    write!(line, "event={event}").unwrap();
+
+    for (key, value) in fields {
+        write!(line, " {key}={value:?}").unwrap();
+    }
+
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        // Losing a trace isn't worth failing the run over
+        let _ = writeln!(sink, "{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(bytes)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_events_with_their_context() {
+        let buffer = Buffer::default();
+
+        crate::trace!("ignored", value = 1);
+        to_writer(buffer.clone());
+
+        set_context(4, None);
+        crate::trace!("parsed", cards = 6);
+        set_context(4, Some(Part::One));
+        crate::trace!("card", number = 1, winners = vec![48, 83], name = "a");
+
+        let text = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        assert_eq!(
+            text,
+            "day=4 event=parsed cards=6\n\
+             day=4 part=1 event=card number=1 winners=[48, 83] name=\"a\"\n"
+        );
+    }
+}